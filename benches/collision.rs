@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, state::app::StatesPlugin, time::TimeUpdateStrategy};
+use criterion::{criterion_group, criterion_main, Criterion};
+use ittokun_bevy_games::{
+    apply_velocity,
+    collision::{Collider, CollisionPlugin},
+    AppState, Velocity,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const TICK: Duration = Duration::from_nanos(16_666_667);
+const ARENA_SIZE: Vec2 = Vec2::new(700.0, 700.0);
+
+const BULLET_COUNT: usize = 5_000;
+const BULLET_RADIUS: f32 = 2.5;
+const BULLET_SPEED: f32 = 400.0;
+
+const TARGET_COUNT: usize = 50;
+const TARGET_SIZE: f32 = 15.0;
+
+const BULLET_LAYER: u32 = 1 << 1;
+const TARGET_LAYER: u32 = 1 << 2;
+
+// Bullets bounce off the arena walls so the field stays dense however long the bench runs
+fn bounce_bullets(mut bullet_query: Query<(&Transform, &mut Velocity)>) {
+    for (transform, mut velocity) in &mut bullet_query {
+        if transform.translation.x.abs() > ARENA_SIZE.x / 2.0 {
+            velocity.x = -velocity.x.abs() * transform.translation.x.signum();
+        }
+        if transform.translation.y.abs() > ARENA_SIZE.y / 2.0 {
+            velocity.y = -velocity.y.abs() * transform.translation.y.signum();
+        }
+    }
+}
+
+fn bullet_hell_app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, StatesPlugin))
+        .insert_state(AppState::InGame)
+        .insert_resource(Time::<Fixed>::from_duration(TICK))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(TICK))
+        .add_plugins(CollisionPlugin)
+        .add_systems(FixedUpdate, (apply_velocity, bounce_bullets));
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut random_position = move || {
+        Vec3::new(
+            rng.gen_range(-ARENA_SIZE.x / 2.0..ARENA_SIZE.x / 2.0),
+            rng.gen_range(-ARENA_SIZE.y / 2.0..ARENA_SIZE.y / 2.0),
+            0.0,
+        )
+    };
+
+    for _ in 0..TARGET_COUNT {
+        app.world_mut().spawn((
+            Transform::from_translation(random_position()),
+            Collider::aabb(Vec2::splat(TARGET_SIZE)).with_layers(TARGET_LAYER, BULLET_LAYER),
+        ));
+    }
+    for i in 0..BULLET_COUNT {
+        let direction = Vec2::from_angle(i as f32).extend(0.0);
+        app.world_mut().spawn((
+            Transform::from_translation(random_position()),
+            Collider::circle(BULLET_RADIUS).with_layers(BULLET_LAYER, TARGET_LAYER),
+            Velocity(direction * BULLET_SPEED),
+        ));
+    }
+
+    app.finish();
+    app.cleanup();
+    // The first update has a zero delta, warm up so every measured update runs exactly one fixed tick
+    app.update();
+    app.update();
+    app
+}
+
+fn bullet_hell(c: &mut Criterion) {
+    let mut app = bullet_hell_app();
+
+    c.bench_function("collision tick with 5000 bullets", |b| {
+        b.iter(|| app.update())
+    });
+}
+
+criterion_group!(benches, bullet_hell);
+criterion_main!(benches);
@@ -1,17 +1,11 @@
-use bevy::{
-    prelude::*,
-    window::PrimaryWindow,
-};
+use bevy::{prelude::*, window::PrimaryWindow};
 use bevy_ecs_ldtk::prelude::*;
 
-use crate::{
-    AppState,
-    WINDOW_SIZE,
-};
+use crate::{AppState, WINDOW_SIZE};
 
 const FONT_SIZE: f32 = 40.0;
-const FONT_COLOR: Color = Color::rgb(0.9, 0.9, 0.9);
-const BG_COLOR: Color = Color::rgb(0.317, 0.337, 0.411);
+const FONT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const BG_COLOR: Color = Color::srgb(0.317, 0.337, 0.411);
 const BG_SIZE: Vec2 = Vec2::new(140.0, 180.0);
 const TEXT_GAP: f32 = 80.0;
 const GAMEOVER_TEXT: &str = "Game Over";
@@ -27,10 +21,7 @@ pub struct Restart;
 #[derive(Component)]
 pub struct BackTitle;
 
-pub fn gameover_setup(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-) {
+pub fn gameover_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     // Gameover
     commands.spawn((
         TextBundle::from_section(
@@ -49,7 +40,7 @@ pub fn gameover_setup(
         }),
         Gameover,
     ));
-    // Restart 
+    // Restart
     commands.spawn((
         TextBundle::from_section(
             RESTART_TEXT,
@@ -96,10 +87,7 @@ pub fn gameover_setup(
                 ..default()
             },
             transform: Transform {
-                translation: Vec3::new(
-                    WINDOW_SIZE.x / 4.0,
-                    WINDOW_SIZE.y / 4.0,
-                    10.0),
+                translation: Vec3::new(WINDOW_SIZE.x / 4.0, WINDOW_SIZE.y / 4.0, 10.0),
                 ..default()
             },
             ..default()
@@ -110,14 +98,21 @@ pub fn gameover_setup(
 
 pub fn gameover_update(
     window_query: Query<&Window, With<PrimaryWindow>>,
-    mouse_event: Res<Input<MouseButton>>,
+    mouse_event: Res<ButtonInput<MouseButton>>,
     gameover_query: Query<Entity, With<Gameover>>,
     restart_query: Query<&Transform, With<Restart>>,
     backtitle_query: Query<&Transform, With<BackTitle>>,
     level_selection: ResMut<LevelSelection>,
     mut commands: Commands,
     mut app_state: ResMut<NextState<AppState>>,
-    mut ldtk_projects: Query<&mut Transform, (With<Handle<LdtkProject>>, Without<Restart>, Without<BackTitle>)>,
+    mut ldtk_projects: Query<
+        &mut Transform,
+        (
+            With<Handle<LdtkProject>>,
+            Without<Restart>,
+            Without<BackTitle>,
+        ),
+    >,
 ) {
     let window = window_query.single();
     let restart_transform = restart_query.single();
@@ -143,8 +138,7 @@ pub fn gameover_update(
             for gameover_entity in gameover_query.iter() {
                 commands.entity(gameover_entity).despawn();
             }
-        }
-        else if backtitle_distance < 40.0 {
+        } else if backtitle_distance < 40.0 {
             // Change game state
             app_state.set(AppState::MainMenu);
             // Change ldtk transform
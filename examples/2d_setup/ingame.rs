@@ -1,14 +1,8 @@
 use bevy::prelude::*;
-use bevy_ecs_ldtk::{
-    prelude::*,
-    utils::grid_coords_to_translation,
-};
+use bevy_ecs_ldtk::{prelude::*, utils::grid_coords_to_translation};
 use std::collections::HashSet;
 
-use crate::{
-    WINDOW_SIZE,
-    AppState,
-};
+use crate::{AppState, WINDOW_SIZE};
 
 const GRID_SIZE: i32 = 16;
 const MAX_LEVEL_SELECTION: usize = 3;
@@ -26,7 +20,7 @@ pub struct Goal;
 pub struct PlayerBundle {
     player: Player,
     #[sprite_sheet_bundle]
-    sprite_sheet_bundle: SpriteSheetBundle,
+    sprite_sheet_bundle: LdtkSpriteSheetBundle,
     #[grid_coords]
     grid_coords: GridCoords,
 }
@@ -40,7 +34,7 @@ pub struct WallBundle {
 pub struct GoalBundle {
     goal: Goal,
     #[sprite_sheet_bundle]
-    sprite_sheet_bundle: SpriteSheetBundle,
+    sprite_sheet_bundle: LdtkSpriteSheetBundle,
     #[grid_coords]
     grid_coords: GridCoords,
 }
@@ -56,7 +50,7 @@ impl LevelWalls {
     fn in_wall(&self, grid_coords: &GridCoords) -> bool {
         grid_coords.x < 0
             || grid_coords.y < 0
-            || grid_coords.x >= self.level_width 
+            || grid_coords.x >= self.level_width
             || grid_coords.y >= self.level_height
             || self.wall_locations.contains(grid_coords)
     }
@@ -85,16 +79,16 @@ pub fn ingame_setup(
 
 pub fn move_player_from_input(
     mut players: Query<&mut GridCoords, With<Player>>,
-    input: Res<Input<KeyCode>>,
+    input: Res<ButtonInput<KeyCode>>,
     level_walls: Res<LevelWalls>,
 ) {
-    let movement_direction = if input.any_just_pressed([KeyCode::W, KeyCode::Up]) {
+    let movement_direction = if input.any_just_pressed([KeyCode::KeyW, KeyCode::ArrowUp]) {
         GridCoords::new(0, 1)
-    } else if input.any_just_pressed([KeyCode::A, KeyCode::Left]) {
+    } else if input.any_just_pressed([KeyCode::KeyA, KeyCode::ArrowLeft]) {
         GridCoords::new(-1, 0)
-    } else if input.any_just_pressed([KeyCode::S, KeyCode::Down]) {
+    } else if input.any_just_pressed([KeyCode::KeyS, KeyCode::ArrowDown]) {
         GridCoords::new(0, -1)
-    } else if input.any_just_pressed([KeyCode::D, KeyCode::Right]) {
+    } else if input.any_just_pressed([KeyCode::KeyD, KeyCode::ArrowRight]) {
         GridCoords::new(1, 0)
     } else {
         return;
@@ -112,9 +106,8 @@ pub fn translate_grid_coords_entities(
     mut grid_coords_entities: Query<(&mut Transform, &GridCoords), Changed<GridCoords>>,
 ) {
     for (mut transform, grid_coords) in grid_coords_entities.iter_mut() {
-        transform.translation =
-            grid_coords_to_translation(*grid_coords, IVec2::splat(GRID_SIZE))
-                .extend(transform.translation.z);
+        transform.translation = grid_coords_to_translation(*grid_coords, IVec2::splat(GRID_SIZE))
+            .extend(transform.translation.z);
     }
 }
 
@@ -154,7 +147,7 @@ pub fn check_goal(
     if players
         .iter()
         .zip(goals.iter())
-        .any(|(player_grid_coords, goal_grid_coords) | player_grid_coords == goal_grid_coords)
+        .any(|(player_grid_coords, goal_grid_coords)| player_grid_coords == goal_grid_coords)
     {
         let indices = match level_selection.into_inner() {
             LevelSelection::Indices(indices) => indices,
@@ -163,15 +156,14 @@ pub fn check_goal(
 
         if indices.level < MAX_LEVEL_SELECTION - 1 {
             indices.level += 1;
-        }
-        else {
+        } else {
             app_state.set(AppState::GameOver);
         }
     }
 }
 
 pub fn update_ingame(
-    keyboard_input: Res<Input<KeyCode>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
     mut app_state: ResMut<NextState<AppState>>,
     mut ldtk_projects: Query<&mut Transform, With<Handle<LdtkProject>>>,
 ) {
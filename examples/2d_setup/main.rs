@@ -1,39 +1,25 @@
+#![allow(clippy::type_complexity, clippy::too_many_arguments)]
+
 use bevy::prelude::*;
 use bevy_ecs_ldtk::prelude::*;
 
-use crate::mainmenu::{
-    mainmenu_setup,
-    mainmenu_update,
-};
+use crate::gameover::{gameover_setup, gameover_update};
 use crate::ingame::{
-    PlayerBundle,
+    cache_wall_locations, check_goal, ingame_setup, move_player_from_input,
+    translate_grid_coords_entities, update_ingame, GoalBundle, LevelWalls, PlayerBundle,
     WallBundle,
-    GoalBundle,
-    LevelWalls,
-    ingame_setup,
-    move_player_from_input,
-    translate_grid_coords_entities,
-    cache_wall_locations,
-    check_goal,
-    update_ingame,
-};
-use crate::pause::{
-    pause_setup,
-    pause_update,
-};
-use crate::gameover::{
-    gameover_setup,
-    gameover_update,
 };
+use crate::mainmenu::{mainmenu_setup, mainmenu_update};
+use crate::pause::{pause_setup, pause_update};
 
-pub mod mainmenu;
+pub mod gameover;
 pub mod ingame;
+pub mod mainmenu;
 pub mod pause;
-pub mod gameover;
 
 pub const GAME_TITLE: &str = "2D Setup";
 pub const WINDOW_SIZE: Vec2 = Vec2::new(800.0, 800.0);
-pub const BG_COLOR: Color = Color::rgb(0.255, 0.251, 0.333);
+pub const BG_COLOR: Color = Color::srgb(0.255, 0.251, 0.333);
 
 #[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default, States)]
 pub enum AppState {
@@ -46,18 +32,19 @@ pub enum AppState {
 
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins
-            .set(WindowPlugin {
-                primary_window: Some(Window {
-                    resolution: WINDOW_SIZE.into(),
-                    title: GAME_TITLE.to_string(),
+        .add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        resolution: WINDOW_SIZE.into(),
+                        title: GAME_TITLE.to_string(),
+                        ..default()
+                    }),
                     ..default()
-                }),
-                ..default()
-            })
-            .set(ImagePlugin::default_nearest())
+                })
+                .set(ImagePlugin::default_nearest()),
         )
-        .add_state::<AppState>()
+        .init_state::<AppState>()
         .insert_resource(ClearColor(BG_COLOR))
         .insert_resource(Time::<Fixed>::from_seconds(1.0 / 60.0))
         // ldtk setup
@@ -74,13 +61,17 @@ fn main() {
         .add_systems(Update, mainmenu_update.run_if(in_state(AppState::MainMenu)))
         // ingame
         .add_systems(OnEnter(AppState::InGame), ingame_setup)
-        .add_systems(Update, (
-            move_player_from_input,
-            translate_grid_coords_entities,
-            cache_wall_locations,
-            check_goal,
-            update_ingame,
-        ).run_if(in_state(AppState::InGame)))
+        .add_systems(
+            Update,
+            (
+                move_player_from_input,
+                translate_grid_coords_entities,
+                cache_wall_locations,
+                check_goal,
+                update_ingame,
+            )
+                .run_if(in_state(AppState::InGame)),
+        )
         // pause
         .add_systems(OnEnter(AppState::Pause), pause_setup)
         .add_systems(Update, pause_update.run_if(in_state(AppState::Pause)))
@@ -88,7 +79,7 @@ fn main() {
         .add_systems(OnEnter(AppState::GameOver), gameover_setup)
         .add_systems(Update, gameover_update.run_if(in_state(AppState::GameOver)))
         .run();
-}   
+}
 
 fn setup_camera(mut commands: Commands) {
     commands.spawn(Camera2dBundle::default());
@@ -1,19 +1,11 @@
-use bevy::{
-    app::AppExit,
-    prelude::*,
-    window::PrimaryWindow,
-};
+use bevy::{app::AppExit, prelude::*, window::PrimaryWindow};
 use bevy_ecs_ldtk::prelude::*;
 
-use crate::{
-    GAME_TITLE,
-    WINDOW_SIZE,
-    AppState,
-};
+use crate::{AppState, GAME_TITLE, WINDOW_SIZE};
 
 const FONT_SIZE: f32 = 40.0;
-const FONT_COLOR: Color = Color::rgb(0.1, 0.1, 0.1);
-const BG_COLOR: Color = Color::rgb(0.9, 0.9, 0.9);
+const FONT_COLOR: Color = Color::srgb(0.1, 0.1, 0.1);
+const BG_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
 const SCREEN_SIZE: Vec2 = Vec2::new(400.0, 400.0);
 const PLAYBTN_TEXT: &str = "Play";
 const QUITBTN_TEXT: &str = "Quit";
@@ -112,7 +104,7 @@ pub fn mainmenu_setup(
 
 pub fn mainmenu_update(
     window_query: Query<&Window, With<PrimaryWindow>>,
-    mouse_event: Res<Input<MouseButton>>,
+    mouse_event: Res<ButtonInput<MouseButton>>,
     mainmenu_query: Query<Entity, With<Mainmenu>>,
     playbtn_query: Query<&Transform, With<PlayBtn>>,
     quitbtn_query: Query<&Transform, With<QuitBtn>>,
@@ -145,10 +137,9 @@ pub fn mainmenu_update(
             for mainmenu_entity in mainmenu_query.iter() {
                 commands.entity(mainmenu_entity).despawn();
             }
-        }
-        else if quitbtn_distance < 40.0 {
+        } else if quitbtn_distance < 40.0 {
             // quit game
-            exit.send(AppExit);
+            exit.send(AppExit::Success);
         }
     }
 }
@@ -1,23 +1,17 @@
 use bevy::prelude::*;
 
-use crate::{
-    WINDOW_SIZE,
-    AppState,
-};
+use crate::{AppState, WINDOW_SIZE};
 
 const FONT_SIZE: f32 = 40.0;
-const FONT_COLOR: Color = Color::rgb(0.1, 0.1, 0.1);
-const BG_COLOR: Color = Color::rgb(0.9, 0.9, 0.9);
+const FONT_COLOR: Color = Color::srgb(0.1, 0.1, 0.1);
+const BG_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
 const BG_SIZE: Vec2 = Vec2::new(80.0, 80.0);
 const PAUSEBTN_TEXT: &str = "Pause";
 
 #[derive(Component)]
 pub struct Pause;
 
-pub fn pause_setup(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-) {
+pub fn pause_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     // Pause
     commands.spawn((
         TextBundle::from_section(
@@ -45,10 +39,7 @@ pub fn pause_setup(
                 ..default()
             },
             transform: Transform {
-                translation: Vec3::new(
-                    WINDOW_SIZE.x / 4.0,
-                    WINDOW_SIZE.y / 4.0,
-                    10.0),
+                translation: Vec3::new(WINDOW_SIZE.x / 4.0, WINDOW_SIZE.y / 4.0, 10.0),
                 ..default()
             },
             ..default()
@@ -58,10 +49,10 @@ pub fn pause_setup(
 }
 
 pub fn pause_update(
-    keyboard_input: Res<Input<KeyCode>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
     pause_query: Query<Entity, With<Pause>>,
     mut commands: Commands,
-    mut app_state: ResMut<NextState<AppState>>
+    mut app_state: ResMut<NextState<AppState>>,
 ) {
     if keyboard_input.just_pressed(KeyCode::Escape) {
         // Changed app state
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use ittokun_bevy_games::{
+    games::doodle_jump::{DoodleJumpPlugin, WINDOW_SIZE},
+    MiniGamePlugin,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                resolution: WINDOW_SIZE.into(),
+                fit_canvas_to_parent: true,
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(MiniGamePlugin)
+        .add_plugins(DoodleJumpPlugin)
+        .run();
+}
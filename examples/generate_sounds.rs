@@ -0,0 +1,168 @@
+//! Writes the synthesized sound effects to `assets/sounds`, so they can be checked or remade:
+//! `cargo run --example generate_sounds`. Each is a few tones or noise under an envelope, 16-bit
+//! mono WAV, and the same every run.
+
+use std::{
+    f32::consts::TAU,
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+
+const SAMPLE_RATE: u32 = 22050;
+
+#[derive(Clone, Copy)]
+enum Wave {
+    Sine,
+    Square,
+    Triangle,
+    Noise,
+}
+
+/// One tone, sliding from `from` to `to` Hz, that fades out over its length.
+#[derive(Clone, Copy)]
+struct Tone {
+    wave: Wave,
+    from: f32,
+    to: f32,
+    secs: f32,
+    /// How fast it fades, 0.0 for evenly to the end.
+    decay: f32,
+}
+
+fn tone(wave: Wave, from: f32, to: f32, secs: f32, decay: f32) -> Tone {
+    Tone {
+        wave,
+        from,
+        to,
+        secs,
+        decay,
+    }
+}
+
+// A fixed seed, so the noise comes out the same every time
+struct Noise(u32);
+
+impl Noise {
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0 as f32 / u32::MAX as f32 * 2.0 - 1.0
+    }
+}
+
+// The tones one after another
+fn render(tones: &[Tone]) -> Vec<f32> {
+    let mut noise = Noise(0x2545_f491);
+    let mut low_passed = 0.0;
+    let mut samples = Vec::new();
+
+    for tone in tones {
+        let count = (tone.secs * SAMPLE_RATE as f32) as usize;
+        let mut phase = 0.0_f32;
+        for i in 0..count {
+            let t = i as f32 / count as f32;
+            let frequency = tone.from + (tone.to - tone.from) * t;
+            phase = (phase + frequency / SAMPLE_RATE as f32).fract();
+
+            let value = match tone.wave {
+                Wave::Sine => (phase * TAU).sin(),
+                Wave::Square => {
+                    if phase < 0.5 {
+                        0.5
+                    } else {
+                        -0.5
+                    }
+                }
+                Wave::Triangle => 1.0 - 4.0 * (phase - 0.5).abs(),
+                // Noise through a low pass that closes from `from` to `to` Hz
+                Wave::Noise => {
+                    let amount = (frequency / SAMPLE_RATE as f32 * TAU).min(1.0);
+                    low_passed += (noise.next() - low_passed) * amount;
+                    low_passed * 2.0
+                }
+            };
+            // A few samples of attack keep the start from clicking
+            let attack = (i as f32 / 40.0).min(1.0);
+            let envelope = (1.0 - t) * (-tone.decay * t).exp();
+            samples.push(value * attack * envelope);
+        }
+    }
+    samples
+}
+
+fn write_wav(path: &Path, samples: &[f32]) -> io::Result<()> {
+    let data_size = samples.len() as u32 * 2;
+    let mut bytes = Vec::with_capacity(44 + data_size as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_size).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16_u32.to_le_bytes());
+    // PCM, one channel
+    bytes.extend_from_slice(&1_u16.to_le_bytes());
+    bytes.extend_from_slice(&1_u16.to_le_bytes());
+    bytes.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    bytes.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());
+    bytes.extend_from_slice(&2_u16.to_le_bytes());
+    bytes.extend_from_slice(&16_u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_size.to_le_bytes());
+    for sample in samples {
+        let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32 * 0.8) as i16;
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    fs::File::create(path)?.write_all(&bytes)
+}
+
+fn main() -> io::Result<()> {
+    use Wave::*;
+
+    let sounds: [(&str, Vec<Tone>); 9] = [
+        ("jump", vec![tone(Square, 220.0, 660.0, 0.18, 2.0)]),
+        (
+            "pickup",
+            vec![
+                tone(Sine, 988.0, 988.0, 0.06, 0.0),
+                tone(Sine, 1319.0, 1319.0, 0.14, 2.0),
+            ],
+        ),
+        ("shoot", vec![tone(Square, 1400.0, 250.0, 0.14, 3.0)]),
+        ("explode", vec![tone(Noise, 3000.0, 150.0, 0.6, 3.0)]),
+        ("pop", vec![tone(Sine, 600.0, 200.0, 0.07, 2.0)]),
+        ("ui_select", vec![tone(Sine, 1200.0, 1200.0, 0.035, 3.0)]),
+        (
+            "ui_confirm",
+            vec![
+                tone(Sine, 784.0, 784.0, 0.05, 0.0),
+                tone(Sine, 1175.0, 1175.0, 0.09, 2.0),
+            ],
+        ),
+        (
+            "game_over",
+            vec![
+                tone(Triangle, 523.0, 523.0, 0.16, 0.0),
+                tone(Triangle, 440.0, 440.0, 0.16, 0.0),
+                tone(Triangle, 349.0, 349.0, 0.16, 0.0),
+                tone(Triangle, 262.0, 262.0, 0.45, 1.0),
+            ],
+        ),
+        (
+            "game_clear",
+            vec![
+                tone(Triangle, 523.0, 523.0, 0.1, 0.0),
+                tone(Triangle, 659.0, 659.0, 0.1, 0.0),
+                tone(Triangle, 784.0, 784.0, 0.1, 0.0),
+                tone(Triangle, 1047.0, 1047.0, 0.45, 1.0),
+            ],
+        ),
+    ];
+
+    let folder = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/sounds");
+    for (name, tones) in sounds {
+        let path = folder.join(format!("{name}.wav"));
+        write_wav(&path, &render(&tones))?;
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
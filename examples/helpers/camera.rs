@@ -3,33 +3,33 @@ use bevy::prelude::*;
 #[allow(dead_code)]
 pub fn movement(
     time: Res<Time>,
-    keyboard_input: Res<Input<KeyCode>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
     mut query: Query<(&mut Transform, &mut OrthographicProjection), With<Camera>>,
 ) {
     for (mut transform, mut ortho) in query.iter_mut() {
         let mut direction = Vec3::ZERO;
 
-        if keyboard_input.any_pressed([KeyCode::A, KeyCode::Left]) {
+        if keyboard_input.any_pressed([KeyCode::KeyA, KeyCode::ArrowLeft]) {
             direction -= Vec3::new(1.0, 0.0, 0.0);
         }
 
-        if keyboard_input.any_pressed([KeyCode::D, KeyCode::Right]) {
+        if keyboard_input.any_pressed([KeyCode::KeyD, KeyCode::ArrowRight]) {
             direction += Vec3::new(1.0, 0.0, 0.0);
         }
 
-        if keyboard_input.any_pressed([KeyCode::W, KeyCode::Up]) {
+        if keyboard_input.any_pressed([KeyCode::KeyW, KeyCode::ArrowUp]) {
             direction += Vec3::new(0.0, 1.0, 0.0);
         }
 
-        if keyboard_input.any_pressed([KeyCode::S, KeyCode::Down]) {
+        if keyboard_input.any_pressed([KeyCode::KeyS, KeyCode::ArrowDown]) {
             direction -= Vec3::new(0.0, 1.0, 0.0);
         }
 
-        if keyboard_input.pressed(KeyCode::Z) {
+        if keyboard_input.pressed(KeyCode::KeyZ) {
             ortho.scale += 0.1;
         }
 
-        if keyboard_input.pressed(KeyCode::X) {
+        if keyboard_input.pressed(KeyCode::KeyX) {
             ortho.scale -= 0.1;
         }
 
@@ -2,35 +2,35 @@ use bevy::prelude::*;
 
 #[allow(dead_code)]
 pub fn movement(
-    keyboard_input: Res<Input<KeyCode>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
     mut player_query: Query<&mut Transform, With<Player>>,
-    time_step: Res<FixedTime>,
+    time_step: Res<Time<Fixed>>,
 ) {
     let mut player_transform = player_query.single_mut();
     let mut direction = Vec2::ZERO;
 
     // Keyboard input
-    if keyboard_input.any_pressed([KeyCode::Up, KeyCode::W]) {
+    if keyboard_input.any_pressed([KeyCode::ArrowUp, KeyCode::KeyW]) {
         direction.y -= 1.0;
     }
-    if keyboard_input.any_pressed([KeyCode::Left, KeyCode::A]) {
+    if keyboard_input.any_pressed([KeyCode::ArrowLeft, KeyCode::KeyA]) {
         direction.x -= 1.0;
     }
-    if keyboard_input.any_pressed([KeyCode::Right, KeyCode::D]) {
+    if keyboard_input.any_pressed([KeyCode::ArrowRight, KeyCode::KeyD]) {
         direction.x += 1.0;
     }
-    if keyboard_input.any_pressed([KeyCode::Down, KeyCode::S]) {
+    if keyboard_input.any_pressed([KeyCode::ArrowDown, KeyCode::KeyS]) {
         direction.y += 1.0;
     }
 
     // Player x movement
     let new_player_position_x = player_transform.translation.x
-        + direction.x * PLAYER_SPEED * time_step.period.as_secs_f32();
+        + direction.x * PLAYER_SPEED * time_step.timestep().as_secs_f32();
     let x_bound = WINDOW_SIZE.x / 2.0 - PLAYER_SIZE.x;
 
     // Player y movement
     let new_player_position_y = player_transform.translation.y
-        + direction.y * PLAYER_SPEED * time_step.period.as_secs_f32();
+        + direction.y * PLAYER_SPEED * time_step.timestep().as_secs_f32();
     let y_bound = WINDOW_SIZE.y / 2.0 - PLAYER_SIZE.y;
 
     player_transform.translation.x = new_player_position_x.clamp(-x_bound, x_bound);
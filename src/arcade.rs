@@ -0,0 +1,312 @@
+use bevy::prelude::*;
+
+use crate::{
+    games::{
+        catch_game::CatchGamePlugin, click_game::ClickGamePlugin, flappy_bird::FlappyBirdPlugin,
+        frogger::FroggerPlugin, run_and_jump::RunAndJumpPlugin, shooting_2d::Shooting2dPlugin,
+        timing_game::TimingGamePlugin,
+    },
+    locale::{Locale, LocalizedString},
+    menu::{spawn_menu_buttons, Menu, MenuButton, MenuChosen, MenuPlugin, MenuSet},
+    mini_game::{Localized, MiniGameInfo, MiniGames},
+    music::{GameMusic, Music, MusicPlayer},
+    settings::SettingsScreen,
+    theme::Theme,
+    ActiveGame, AppState,
+};
+
+/// The size the arcade's window opens at, the games letterbox into it.
+pub const WINDOW_SIZE: Vec2 = Vec2::new(800.0, 600.0);
+
+const HUB_PADDING: Val = Val::Px(20.0);
+const HUB_GAP: Val = Val::Px(15.0);
+const HUB_TITLE_FONT_SIZE: f32 = 50.0;
+
+const CARD_WIDTH: Val = Val::Px(170.0);
+const CARD_HEIGHT: Val = Val::Px(200.0);
+const CARD_PADDING: Val = Val::Px(10.0);
+const CARD_GAP: Val = Val::Px(8.0);
+const CARD_TITLE_FONT_SIZE: f32 = 24.0;
+const CARD_DESCRIPTION_FONT_SIZE: f32 = 16.0;
+const CARD_CONTROLS_FONT_SIZE: f32 = 14.0;
+
+/// A card of the hub, by `MiniGameInfo::id`, labelled with the game's name.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct GameButton {
+    pub id: &'static str,
+    pub name: Localized,
+}
+
+impl From<&MiniGameInfo> for GameButton {
+    fn from(info: &MiniGameInfo) -> Self {
+        Self {
+            id: info.id,
+            name: info.name,
+        }
+    }
+}
+
+/// A button of the hub, one per game and one for the settings after them.
+#[derive(Component, Clone, Copy, Eq, PartialEq, Debug)]
+pub enum HubButton {
+    Game(GameButton),
+    Settings,
+}
+
+impl MenuButton for HubButton {
+    fn label(self) -> LocalizedString {
+        match self {
+            HubButton::Game(game) => game.name.into(),
+            HubButton::Settings => "settings".into(),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct ArcadePanel;
+
+/// Hosts every game in one app behind a hub listing them from `MiniGames`, in the order their
+/// plugins are added here. Choosing a game makes it the
+/// `ActiveGame`, and going back to its title screen from a result or the pause menu returns to
+/// the hub.
+pub struct ArcadePlugin;
+
+impl Plugin for ArcadePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            TimingGamePlugin,
+            ClickGamePlugin,
+            Shooting2dPlugin,
+            FroggerPlugin,
+            FlappyBirdPlugin,
+            CatchGamePlugin,
+            RunAndJumpPlugin,
+        ))
+        .add_plugins(MenuPlugin::<HubButton>::default())
+        .add_systems(Startup, set_hub_track)
+        .add_systems(OnEnter(ActiveGame(None)), (despawn_game, spawn_hub).chain())
+        .add_systems(
+            Update,
+            choose_game
+                .after(MenuSet)
+                .run_if(in_state(ActiveGame(None))),
+        )
+        // The settings cover the hub, which comes back in their language
+        .add_systems(
+            OnEnter(SettingsScreen::Open),
+            (despawn_hub_panel, remove_hub_menu).run_if(in_state(ActiveGame(None))),
+        )
+        .add_systems(
+            OnExit(SettingsScreen::Open),
+            return_from_settings.run_if(in_state(ActiveGame(None))),
+        )
+        .add_systems(OnExit(ActiveGame(None)), (despawn_hub, remove_hub_menu))
+        .add_systems(OnEnter(AppState::MainMenu), return_to_hub);
+    }
+}
+
+// The games' title music, carrying on into a game's title screen
+fn set_hub_track(asset_server: Res<AssetServer>, mut music_player: ResMut<MusicPlayer>) {
+    music_player.set_hub_track(GameMusic::DEFAULT.title, &asset_server);
+}
+
+// What the game left behind, its cameras included. Music carries on into the hub.
+fn despawn_game(
+    mut commands: Commands,
+    entities: Query<Entity, (Without<Window>, Without<Music>)>,
+) {
+    for entity in &entities {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn spawn_hub(
+    mut commands: Commands,
+    locale: Res<Locale>,
+    theme: Res<Theme>,
+    mini_games: Res<MiniGames>,
+    mut transitions: EventReader<StateTransitionEvent<ActiveGame>>,
+) {
+    // The game just left stays selected
+    let last_game = transitions
+        .read()
+        .last()
+        .and_then(|transition| transition.exited)
+        .and_then(|ActiveGame(id)| id);
+    let selected = last_game
+        .and_then(|id| mini_games.get(id))
+        .map(|info| HubButton::Game(info.into()));
+
+    commands.insert_resource(ClearColor(theme.background));
+    commands.spawn((Camera2dBundle::default(), ArcadePanel));
+    spawn_hub_panel(&mut commands, &locale, &theme, &mini_games, selected);
+}
+
+fn return_from_settings(
+    mut commands: Commands,
+    locale: Res<Locale>,
+    theme: Res<Theme>,
+    mini_games: Res<MiniGames>,
+) {
+    spawn_hub_panel(
+        &mut commands,
+        &locale,
+        &theme,
+        &mini_games,
+        Some(HubButton::Settings),
+    );
+}
+
+fn spawn_hub_panel(
+    commands: &mut Commands,
+    locale: &Locale,
+    theme: &Theme,
+    mini_games: &MiniGames,
+    selected: Option<HubButton>,
+) {
+    let menu = Menu::new(
+        mini_games
+            .iter()
+            .map(|info| HubButton::Game(info.into()))
+            .chain([HubButton::Settings])
+            .collect(),
+        selected,
+    );
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    padding: UiRect::all(HUB_PADDING),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: HUB_GAP,
+                    ..default()
+                },
+                ..default()
+            },
+            ArcadePanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                locale.get("arcade"),
+                locale.text_style(HUB_TITLE_FONT_SIZE, theme.panel_text),
+            ));
+
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_wrap: FlexWrap::Wrap,
+                        justify_content: JustifyContent::Center,
+                        row_gap: HUB_GAP,
+                        column_gap: HUB_GAP,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for info in mini_games.iter() {
+                        spawn_game_card(parent, info, locale, theme);
+                    }
+                });
+
+            spawn_menu_buttons(
+                parent,
+                &[HubButton::Settings],
+                FlexDirection::Row,
+                locale,
+                theme,
+            );
+        });
+
+    commands.insert_resource(menu);
+}
+
+fn spawn_game_card(parent: &mut ChildBuilder, info: &MiniGameInfo, locale: &Locale, theme: &Theme) {
+    let text = |value: Localized, font_size, color| {
+        TextBundle::from_section(locale.pick(value), locale.text_style(font_size, color))
+    };
+
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: CARD_WIDTH,
+                    height: CARD_HEIGHT,
+                    padding: UiRect::all(CARD_PADDING),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: CARD_GAP,
+                    ..default()
+                },
+                background_color: theme.button.into(),
+                ..default()
+            },
+            HubButton::Game(info.into()),
+        ))
+        .with_children(|parent| {
+            parent.spawn(text(info.name, CARD_TITLE_FONT_SIZE, theme.button_text));
+            parent.spawn(text(
+                info.description,
+                CARD_DESCRIPTION_FONT_SIZE,
+                theme.button_text,
+            ));
+            parent.spawn(text(
+                info.controls,
+                CARD_CONTROLS_FONT_SIZE,
+                theme.button_hint,
+            ));
+        });
+}
+
+fn choose_game(
+    mut chosen_events: EventReader<MenuChosen<HubButton>>,
+    mut active_game: ResMut<NextState<ActiveGame>>,
+    mut settings_screen: ResMut<NextState<SettingsScreen>>,
+) {
+    match chosen_events.read().last().copied() {
+        Some(MenuChosen(HubButton::Game(GameButton { id, .. }))) => {
+            active_game.set(ActiveGame(Some(id)));
+        }
+        Some(MenuChosen(HubButton::Settings)) => settings_screen.set(SettingsScreen::Open),
+        None => {}
+    }
+}
+
+fn despawn_hub(mut commands: Commands, panel_query: Query<Entity, With<ArcadePanel>>) {
+    for panel_entity in &panel_query {
+        commands.entity(panel_entity).despawn_recursive();
+    }
+}
+
+// The camera stays for the settings
+fn despawn_hub_panel(
+    mut commands: Commands,
+    panel_query: Query<Entity, (With<ArcadePanel>, Without<Camera>)>,
+) {
+    for panel_entity in &panel_query {
+        commands.entity(panel_entity).despawn_recursive();
+    }
+}
+
+fn remove_hub_menu(mut commands: Commands) {
+    commands.remove_resource::<Menu<HubButton>>();
+}
+
+// A game's title screen is entered fresh from `Loading` when the game starts, and again from a
+// result or the pause menu, which is where the hub takes over
+fn return_to_hub(
+    mut transitions: EventReader<StateTransitionEvent<AppState>>,
+    mut active_game: ResMut<NextState<ActiveGame>>,
+) {
+    let left_a_run = transitions.read().last().is_some_and(|transition| {
+        transition
+            .exited
+            .is_some_and(|exited| exited != AppState::Loading)
+    });
+
+    if left_a_run {
+        active_game.set(ActiveGame(None));
+    }
+}
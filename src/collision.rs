@@ -0,0 +1,488 @@
+use bevy::{
+    math::bounding::{Aabb2d, BoundingCircle, BoundingVolume, IntersectsVolume},
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{spatial_hash::SpatialHash, AppState};
+
+pub const DEFAULT_LAYER: u32 = 1;
+
+/// Which side of the other collider was hit, seen from the entity the event is about.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Collision {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Inside,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ColliderShape {
+    Aabb(Vec2),
+    Circle(f32),
+}
+
+impl ColliderShape {
+    pub fn bounding_box(&self, position: Vec2) -> Aabb2d {
+        match self {
+            ColliderShape::Aabb(size) => Aabb2d::new(position, *size / 2.0),
+            ColliderShape::Circle(radius) => Aabb2d::new(position, Vec2::splat(*radius)),
+        }
+    }
+}
+
+/// A collider only touches colliders whose `layers` are in its `mask`, and the other way round.
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
+pub struct Collider {
+    pub shape: ColliderShape,
+    pub layers: u32,
+    pub mask: u32,
+}
+
+impl Collider {
+    pub fn aabb(size: Vec2) -> Self {
+        Self {
+            shape: ColliderShape::Aabb(size),
+            layers: DEFAULT_LAYER,
+            mask: u32::MAX,
+        }
+    }
+
+    pub fn circle(radius: f32) -> Self {
+        Self {
+            shape: ColliderShape::Circle(radius),
+            layers: DEFAULT_LAYER,
+            mask: u32::MAX,
+        }
+    }
+
+    pub fn with_layers(mut self, layers: u32, mask: u32) -> Self {
+        self.layers = layers;
+        self.mask = mask;
+        self
+    }
+
+    pub fn interacts_with(&self, other: &Collider) -> bool {
+        self.layers & other.mask != 0 && other.layers & self.mask != 0
+    }
+}
+
+/// The plane colliders are projected onto. 3D games on a ground grid use `Xz`.
+#[derive(Resource, Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum CollisionPlane {
+    #[default]
+    Xy,
+    Xz,
+}
+
+impl CollisionPlane {
+    pub fn project(self, translation: Vec3) -> Vec2 {
+        match self {
+            CollisionPlane::Xy => translation.truncate(),
+            CollisionPlane::Xz => Vec2::new(translation.x, translation.z),
+        }
+    }
+}
+
+/// Sent once for each side of a pair when two colliders start touching.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CollisionStarted {
+    pub entity: Entity,
+    pub other: Entity,
+    pub side: Collision,
+}
+
+/// Sent once for each side of a pair when two colliders stop touching or one of them is gone.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CollisionEnded {
+    pub entity: Entity,
+    pub other: Entity,
+}
+
+/// Every pair touching as of the last detection pass, stored in both directions.
+#[derive(Resource, Default, Debug)]
+pub struct Contacts(HashMap<(Entity, Entity), Collision>);
+
+impl Contacts {
+    pub fn of(&self, entity: Entity) -> impl Iterator<Item = (Entity, Collision)> + '_ {
+        self.0
+            .iter()
+            .filter(move |((a, _), _)| *a == entity)
+            .map(|((_, b), side)| (*b, *side))
+    }
+
+    pub fn contains(&self, entity: Entity, other: Entity) -> bool {
+        self.0.contains_key(&(entity, other))
+    }
+}
+
+#[derive(SystemSet, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct CollisionSet;
+
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CollisionPlane>()
+            .init_resource::<SpatialHash>()
+            .init_resource::<Contacts>()
+            .add_event::<CollisionStarted>()
+            .add_event::<CollisionEnded>()
+            .add_systems(
+                FixedUpdate,
+                (update_spatial_hash, detect_collisions)
+                    .chain()
+                    .in_set(CollisionSet)
+                    .run_if(in_state(AppState::InGame)),
+            );
+    }
+}
+
+/// Returns the side of `b` that `a` hit, or `None` when they do not overlap.
+pub fn contact(
+    a_pos: Vec2,
+    a: &ColliderShape,
+    b_pos: Vec2,
+    b: &ColliderShape,
+) -> Option<Collision> {
+    match (a, b) {
+        (ColliderShape::Aabb(a_size), ColliderShape::Aabb(b_size)) => aabb_contact(
+            Aabb2d::new(a_pos, *a_size / 2.0),
+            Aabb2d::new(b_pos, *b_size / 2.0),
+        ),
+        (ColliderShape::Circle(a_radius), ColliderShape::Aabb(b_size)) => {
+            let a = BoundingCircle::new(a_pos, *a_radius);
+            let b = Aabb2d::new(b_pos, *b_size / 2.0);
+            a.intersects(&b)
+                .then(|| side_from_offset(a_pos - b.closest_point(a_pos)))
+        }
+        (ColliderShape::Aabb(a_size), ColliderShape::Circle(b_radius)) => {
+            let a = Aabb2d::new(a_pos, *a_size / 2.0);
+            let b = BoundingCircle::new(b_pos, *b_radius);
+            a.intersects(&b)
+                .then(|| side_from_offset(a.center() - b.closest_point(a.center())))
+        }
+        (ColliderShape::Circle(a_radius), ColliderShape::Circle(b_radius)) => {
+            let a = BoundingCircle::new(a_pos, *a_radius);
+            let b = BoundingCircle::new(b_pos, *b_radius);
+            a.intersects(&b).then(|| side_from_offset(a_pos - b_pos))
+        }
+    }
+}
+
+fn aabb_contact(a: Aabb2d, b: Aabb2d) -> Option<Collision> {
+    if a.min.x >= b.max.x || a.max.x <= b.min.x || a.min.y >= b.max.y || a.max.y <= b.min.y {
+        return None;
+    }
+
+    let (x_collision, x_depth) = if a.min.x < b.min.x && a.max.x > b.min.x && a.max.x < b.max.x {
+        (Collision::Left, b.min.x - a.max.x)
+    } else if a.min.x > b.min.x && a.min.x < b.max.x && a.max.x > b.max.x {
+        (Collision::Right, a.min.x - b.max.x)
+    } else {
+        (Collision::Inside, -f32::INFINITY)
+    };
+
+    let (y_collision, y_depth) = if a.min.y < b.min.y && a.max.y > b.min.y && a.max.y < b.max.y {
+        (Collision::Bottom, b.min.y - a.max.y)
+    } else if a.min.y > b.min.y && a.min.y < b.max.y && a.max.y > b.max.y {
+        (Collision::Top, a.min.y - b.max.y)
+    } else {
+        (Collision::Inside, -f32::INFINITY)
+    };
+
+    // The shallowest axis is the side that was hit
+    if y_depth.abs() < x_depth.abs() {
+        Some(y_collision)
+    } else {
+        Some(x_collision)
+    }
+}
+
+fn side_from_offset(offset: Vec2) -> Collision {
+    if offset == Vec2::ZERO {
+        Collision::Inside
+    } else if offset.x.abs() > offset.y.abs() {
+        if offset.x < 0.0 {
+            Collision::Left
+        } else {
+            Collision::Right
+        }
+    } else if offset.y > 0.0 {
+        Collision::Top
+    } else {
+        Collision::Bottom
+    }
+}
+
+pub fn update_spatial_hash(
+    collider_query: Query<(Entity, &Transform, &Collider)>,
+    plane: Res<CollisionPlane>,
+    mut spatial_hash: ResMut<SpatialHash>,
+) {
+    spatial_hash.clear();
+
+    for (entity, transform, collider) in &collider_query {
+        let position = plane.project(transform.translation);
+        spatial_hash.insert(entity, collider.shape.bounding_box(position));
+    }
+}
+
+pub fn detect_collisions(
+    collider_query: Query<(Entity, &Transform, &Collider)>,
+    plane: Res<CollisionPlane>,
+    spatial_hash: Res<SpatialHash>,
+    mut contacts: ResMut<Contacts>,
+    mut started_events: EventWriter<CollisionStarted>,
+    mut ended_events: EventWriter<CollisionEnded>,
+) {
+    let mut current = HashMap::default();
+
+    for (a_entity, a_transform, a_collider) in &collider_query {
+        let a_pos = plane.project(a_transform.translation);
+
+        for b_entity in spatial_hash.query(a_collider.shape.bounding_box(a_pos)) {
+            // Test each pair from its lower entity, skipping pairs already found through another shared cell
+            if b_entity <= a_entity || current.contains_key(&(a_entity, b_entity)) {
+                continue;
+            }
+            let Ok((_, b_transform, b_collider)) = collider_query.get(b_entity) else {
+                continue;
+            };
+            if !a_collider.interacts_with(b_collider) {
+                continue;
+            }
+
+            let b_pos = plane.project(b_transform.translation);
+            if let Some(side) = contact(a_pos, &a_collider.shape, b_pos, &b_collider.shape) {
+                current.insert((a_entity, b_entity), side);
+                current.insert((b_entity, a_entity), flip(side));
+            }
+        }
+    }
+
+    for (&(entity, other), &side) in &current {
+        if !contacts.0.contains_key(&(entity, other)) {
+            started_events.send(CollisionStarted {
+                entity,
+                other,
+                side,
+            });
+        }
+    }
+    for &(entity, other) in contacts.0.keys() {
+        if !current.contains_key(&(entity, other)) {
+            ended_events.send(CollisionEnded { entity, other });
+        }
+    }
+
+    contacts.0 = current;
+}
+
+fn flip(side: Collision) -> Collision {
+    match side {
+        Collision::Left => Collision::Right,
+        Collision::Right => Collision::Left,
+        Collision::Top => Collision::Bottom,
+        Collision::Bottom => Collision::Top,
+        Collision::Inside => Collision::Inside,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    const SQUARE: ColliderShape = ColliderShape::Aabb(Vec2::splat(10.0));
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<CollisionPlane>();
+        world.init_resource::<SpatialHash>();
+        world.init_resource::<Contacts>();
+        world.init_resource::<Events<CollisionStarted>>();
+        world.init_resource::<Events<CollisionEnded>>();
+        world
+    }
+
+    fn spawn(world: &mut World, collider: Collider, position: Vec2) -> Entity {
+        world
+            .spawn((collider, Transform::from_translation(position.extend(0.0))))
+            .id()
+    }
+
+    // One detection pass, returning the pairs that started and ended touching
+    fn detect(world: &mut World) -> (Vec<(Entity, Entity, Collision)>, Vec<(Entity, Entity)>) {
+        world.run_system_once(update_spatial_hash);
+        world.run_system_once(detect_collisions);
+        let started = world
+            .resource_mut::<Events<CollisionStarted>>()
+            .drain()
+            .map(|event| (event.entity, event.other, event.side))
+            .collect();
+        let ended = world
+            .resource_mut::<Events<CollisionEnded>>()
+            .drain()
+            .map(|event| (event.entity, event.other))
+            .collect();
+        (started, ended)
+    }
+
+    #[test]
+    fn contact_is_the_side_of_the_other_collider() {
+        for (offset, side) in [
+            (Vec2::new(-8.0, 1.0), Collision::Left),
+            (Vec2::new(8.0, -1.0), Collision::Right),
+            (Vec2::new(1.0, 8.0), Collision::Top),
+            (Vec2::new(-1.0, -8.0), Collision::Bottom),
+        ] {
+            assert_eq!(
+                contact(offset, &SQUARE, Vec2::ZERO, &SQUARE),
+                Some(side),
+                "{offset}"
+            );
+            let circle = ColliderShape::Circle(5.0);
+            assert_eq!(
+                contact(offset, &circle, Vec2::ZERO, &SQUARE),
+                Some(side),
+                "{offset}"
+            );
+            assert_eq!(
+                contact(offset, &SQUARE, Vec2::ZERO, &circle),
+                Some(side),
+                "{offset}"
+            );
+            assert_eq!(
+                contact(offset, &circle, Vec2::ZERO, &circle),
+                Some(side),
+                "{offset}"
+            );
+        }
+
+        let big = ColliderShape::Aabb(Vec2::splat(40.0));
+        assert_eq!(
+            contact(Vec2::ONE, &SQUARE, Vec2::ZERO, &big),
+            Some(Collision::Inside)
+        );
+        // Edges that only touch don't overlap
+        assert_eq!(
+            contact(Vec2::new(10.0, 0.0), &SQUARE, Vec2::ZERO, &SQUARE),
+            None
+        );
+        assert_eq!(
+            contact(Vec2::new(25.0, 25.0), &SQUARE, Vec2::ZERO, &big),
+            None
+        );
+    }
+
+    #[test]
+    fn layers_only_touch_the_masks_they_are_in() {
+        let player = Collider::aabb(Vec2::ONE).with_layers(0b01, 0b10);
+        let enemy = Collider::aabb(Vec2::ONE).with_layers(0b10, 0b01);
+        let other_enemy = Collider::aabb(Vec2::ONE).with_layers(0b10, 0b01);
+        let ghost = Collider::aabb(Vec2::ONE).with_layers(0b10, 0);
+
+        assert!(player.interacts_with(&enemy) && enemy.interacts_with(&player));
+        assert!(!enemy.interacts_with(&other_enemy));
+        // Both sides have to agree
+        assert!(!player.interacts_with(&ghost) && !ghost.interacts_with(&player));
+
+        let mut world = world();
+        let player = spawn(&mut world, player, Vec2::ZERO);
+        let enemy = spawn(&mut world, enemy, Vec2::ZERO);
+        let other_enemy = spawn(&mut world, other_enemy, Vec2::ZERO);
+        let ghost = spawn(&mut world, ghost, Vec2::ZERO);
+        let (started, _) = detect(&mut world);
+        assert_eq!(started.len(), 4);
+        let contacts = world.resource::<Contacts>();
+        assert!(contacts.contains(player, enemy) && contacts.contains(enemy, player));
+        assert!(contacts.contains(player, other_enemy));
+        assert!(!contacts.contains(enemy, other_enemy));
+        assert_eq!(contacts.of(player).count(), 2);
+        assert_eq!(contacts.of(ghost).count(), 0);
+    }
+
+    #[test]
+    fn each_side_of_a_pair_starts_and_ends_once() {
+        let mut world = world();
+        let a = spawn(&mut world, Collider::aabb(Vec2::splat(10.0)), Vec2::ZERO);
+        let b = spawn(
+            &mut world,
+            Collider::aabb(Vec2::splat(10.0)),
+            Vec2::new(8.0, 0.0),
+        );
+
+        let (started, ended) = detect(&mut world);
+        assert_eq!(started.len(), 2);
+        assert!(started.contains(&(a, b, Collision::Left)));
+        assert!(started.contains(&(b, a, Collision::Right)));
+        assert!(ended.is_empty());
+
+        // Still touching
+        assert_eq!(detect(&mut world), (Vec::new(), Vec::new()));
+
+        world.get_mut::<Transform>(b).unwrap().translation.x = 20.0;
+        let (started, ended) = detect(&mut world);
+        assert!(started.is_empty());
+        assert_eq!(ended.len(), 2);
+        assert!(ended.contains(&(a, b)) && ended.contains(&(b, a)));
+        assert_eq!(detect(&mut world), (Vec::new(), Vec::new()));
+
+        // A despawned collider ends its contacts too
+        world.get_mut::<Transform>(b).unwrap().translation.x = 8.0;
+        assert_eq!(detect(&mut world).0.len(), 2);
+        world.despawn(b);
+        let (started, ended) = detect(&mut world);
+        assert!(started.is_empty());
+        assert_eq!(ended.len(), 2);
+    }
+
+    #[test]
+    fn colliders_spanning_many_cells_are_found_from_each_and_paired_once() {
+        let mut spatial_hash = SpatialHash::new(10.0);
+        let wall = Entity::from_raw(1);
+        // Covers cells -3..=2 on x and 0 on y
+        spatial_hash.insert(wall, Aabb2d::new(Vec2::new(0.0, 5.0), Vec2::new(25.0, 1.0)));
+
+        for x in [-25.0, -5.0, 5.0, 24.0] {
+            let found: Vec<Entity> = spatial_hash
+                .query(Aabb2d::new(Vec2::new(x, 5.0), Vec2::ONE))
+                .collect();
+            assert_eq!(found, [wall], "{x}");
+        }
+        assert_eq!(
+            spatial_hash
+                .query(Aabb2d::new(Vec2::new(0.0, 20.0), Vec2::ONE))
+                .count(),
+            0
+        );
+        // Once per shared cell
+        assert_eq!(
+            spatial_hash
+                .query(Aabb2d::new(Vec2::new(0.0, 5.0), Vec2::new(25.0, 1.0)))
+                .count(),
+            6
+        );
+
+        // Across several shared cells a pair is still only reported once per side
+        let mut world = world();
+        let floor = spawn(
+            &mut world,
+            Collider::aabb(Vec2::new(1000.0, 20.0)),
+            Vec2::ZERO,
+        );
+        let block = spawn(
+            &mut world,
+            Collider::aabb(Vec2::new(300.0, 20.0)),
+            Vec2::new(0.0, 15.0),
+        );
+        let (started, _) = detect(&mut world);
+        assert_eq!(started.len(), 2);
+        assert!(started.contains(&(block, floor, Collision::Top)));
+        assert!(started.contains(&(floor, block, Collision::Bottom)));
+    }
+}
@@ -0,0 +1,259 @@
+use bevy::prelude::*;
+
+/// Lists how many entities with a marker component there are in the debug overlay.
+pub trait DebugOverlayAppExt {
+    /// Counts the entities with `C` under `name`, summed with other markers of the same name.
+    /// Does nothing without the `debug-overlay` feature.
+    fn count_in_debug_overlay<C: Component>(&mut self, name: &'static str) -> &mut Self;
+}
+
+impl DebugOverlayAppExt for App {
+    #[cfg(feature = "debug-overlay")]
+    fn count_in_debug_overlay<C: Component>(&mut self, name: &'static str) -> &mut Self {
+        overlay::count::<C>(self, name);
+        self
+    }
+
+    #[cfg(not(feature = "debug-overlay"))]
+    fn count_in_debug_overlay<C: Component>(&mut self, _name: &'static str) -> &mut Self {
+        self
+    }
+}
+
+#[cfg(feature = "debug-overlay")]
+pub use overlay::{DebugOverlay, DebugOverlayPlugin, DebugOverlayText};
+
+#[cfg(feature = "debug-overlay")]
+mod overlay {
+    use std::fmt::Write;
+
+    use bevy::{
+        diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin},
+        gizmos::config::GizmoConfigStore,
+        input::InputSystem,
+        math::Dir3,
+        prelude::*,
+    };
+
+    use crate::{
+        collision::{Collider, ColliderShape, CollisionPlane},
+        loading::UiFonts,
+        play_area::PlayArea,
+        rng::GameRng,
+        AppState,
+    };
+
+    const OVERLAY_TOGGLE_KEY: KeyCode = KeyCode::F3;
+    const OVERLAY_FONT_SIZE: f32 = 16.0;
+    const OVERLAY_PADDING: Val = Val::Px(5.0);
+    const OVERLAY_TEXT_COLOR: Color = Color::srgb(1.0, 1.0, 0.0);
+    const OVERLAY_BACKGROUND_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+    const COLLIDER_GIZMO_COLOR: Color = Color::srgb(0.0, 1.0, 0.0);
+    const PLAY_AREA_GIZMO_COLOR: Color = Color::srgb(1.0, 0.0, 1.0);
+
+    /// Whether the overlay is shown, toggled with F3, and the entity counts it lists.
+    #[derive(Resource, Default, Debug)]
+    pub struct DebugOverlay {
+        pub visible: bool,
+        counts: Vec<(&'static str, usize)>,
+    }
+
+    impl DebugOverlay {
+        /// How many entities were counted under `name` this frame.
+        pub fn count(&self, name: &str) -> Option<usize> {
+            self.counts
+                .iter()
+                .find(|(counted, _)| *counted == name)
+                .map(|(_, count)| *count)
+        }
+    }
+
+    #[derive(Component)]
+    pub struct DebugOverlayText;
+
+    #[derive(SystemSet, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+    enum DebugOverlaySet {
+        Reset,
+        Count,
+        Show,
+    }
+
+    pub(super) fn count<C: Component>(app: &mut App, name: &'static str) {
+        app.init_resource::<DebugOverlay>();
+        let mut overlay = app.world_mut().resource_mut::<DebugOverlay>();
+        if overlay.count(name).is_none() {
+            overlay.counts.push((name, 0));
+        }
+
+        app.add_systems(
+            PostUpdate,
+            (move |query: Query<(), With<C>>, mut overlay: ResMut<DebugOverlay>| {
+                let count = query.iter().count();
+                let total = overlay
+                    .counts
+                    .iter_mut()
+                    .find(|(counted, _)| *counted == name);
+                if let Some((_, total)) = total {
+                    *total += count;
+                }
+            })
+            .in_set(DebugOverlaySet::Count),
+        );
+    }
+
+    /// Shows FPS and frame time, the `AppState`, the RNG seed and the entity counts in a corner,
+    /// and outlines every collider and the play area with gizmos. F3 toggles it.
+    pub struct DebugOverlayPlugin;
+
+    impl Plugin for DebugOverlayPlugin {
+        fn build(&self, app: &mut App) {
+            if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+                app.add_plugins(FrameTimeDiagnosticsPlugin);
+            }
+
+            app.init_resource::<DebugOverlay>()
+                .configure_sets(
+                    PostUpdate,
+                    (
+                        DebugOverlaySet::Reset,
+                        DebugOverlaySet::Count,
+                        DebugOverlaySet::Show,
+                    )
+                        .chain(),
+                )
+                .add_systems(PreUpdate, toggle_overlay.after(InputSystem))
+                .add_systems(PostUpdate, reset_counts.in_set(DebugOverlaySet::Reset))
+                .add_systems(
+                    PostUpdate,
+                    (
+                        show_overlay,
+                        draw_gizmos.run_if(resource_exists::<GizmoConfigStore>),
+                    )
+                        .in_set(DebugOverlaySet::Show),
+                );
+        }
+    }
+
+    // Taken before the games see it, so it never starts a run as "any key"
+    fn toggle_overlay(mut inkey: ResMut<ButtonInput<KeyCode>>, mut overlay: ResMut<DebugOverlay>) {
+        if inkey.clear_just_pressed(OVERLAY_TOGGLE_KEY) {
+            overlay.visible = !overlay.visible;
+        }
+    }
+
+    fn reset_counts(mut overlay: ResMut<DebugOverlay>) {
+        for (_, count) in &mut overlay.counts {
+            *count = 0;
+        }
+    }
+
+    // Spawned whenever it's missing, as runs and the arcade despawn everything they don't know
+    fn show_overlay(
+        mut commands: Commands,
+        overlay: Res<DebugOverlay>,
+        diagnostics: Option<Res<DiagnosticsStore>>,
+        app_state: Option<Res<State<AppState>>>,
+        rng: Option<Res<GameRng>>,
+        fonts: Res<UiFonts>,
+        mut text_query: Query<(Entity, &mut Text), With<DebugOverlayText>>,
+    ) {
+        if !overlay.visible {
+            for (entity, _) in &text_query {
+                commands.entity(entity).despawn();
+            }
+            return;
+        }
+
+        let smoothed = |path| {
+            diagnostics
+                .as_ref()
+                .and_then(|diagnostics| diagnostics.get(path))
+                .and_then(|diagnostic| diagnostic.smoothed())
+                .unwrap_or(0.0)
+        };
+        let mut value = format!(
+            "FPS {:.0} ({:.1} ms)\n",
+            smoothed(&FrameTimeDiagnosticsPlugin::FPS),
+            smoothed(&FrameTimeDiagnosticsPlugin::FRAME_TIME),
+        );
+        match app_state {
+            Some(app_state) => writeln!(value, "State {:?}", app_state.get()),
+            None => writeln!(value, "State -"),
+        }
+        .unwrap();
+        if let Some(rng) = rng {
+            writeln!(value, "Seed {}", rng.seed()).unwrap();
+        }
+        for (name, count) in &overlay.counts {
+            writeln!(value, "{name} {count}").unwrap();
+        }
+        let value = value.trim_end().to_string();
+
+        if let Ok((_, mut text)) = text_query.get_single_mut() {
+            if text.sections[0].value != value {
+                text.sections[0].value = value;
+            }
+            return;
+        }
+
+        commands.spawn((
+            TextBundle {
+                // Above every menu
+                z_index: ZIndex::Global(i32::MAX),
+                ..TextBundle::from_section(
+                    value,
+                    TextStyle {
+                        font: fonts.mono.clone(),
+                        font_size: OVERLAY_FONT_SIZE,
+                        color: OVERLAY_TEXT_COLOR,
+                    },
+                )
+            }
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: OVERLAY_PADDING,
+                right: OVERLAY_PADDING,
+                padding: UiRect::all(OVERLAY_PADDING),
+                ..default()
+            })
+            .with_background_color(OVERLAY_BACKGROUND_COLOR),
+            DebugOverlayText,
+        ));
+    }
+
+    fn draw_gizmos(
+        mut gizmos: Gizmos,
+        overlay: Res<DebugOverlay>,
+        collision_plane: Res<CollisionPlane>,
+        play_area: Option<Res<PlayArea>>,
+        collider_query: Query<(&GlobalTransform, &Collider)>,
+    ) {
+        if !overlay.visible {
+            return;
+        }
+
+        for (transform, collider) in &collider_query {
+            let translation = transform.translation();
+            match (*collision_plane, collider.shape) {
+                (CollisionPlane::Xy, ColliderShape::Aabb(size)) => {
+                    gizmos.rect_2d(translation.truncate(), 0.0, size, COLLIDER_GIZMO_COLOR);
+                }
+                (CollisionPlane::Xy, ColliderShape::Circle(radius)) => {
+                    gizmos.circle_2d(translation.truncate(), radius, COLLIDER_GIZMO_COLOR);
+                }
+                (CollisionPlane::Xz, ColliderShape::Aabb(size)) => {
+                    let rotation = Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2);
+                    gizmos.rect(translation, rotation, size, COLLIDER_GIZMO_COLOR);
+                }
+                (CollisionPlane::Xz, ColliderShape::Circle(radius)) => {
+                    gizmos.circle(translation, Dir3::Y, radius, COLLIDER_GIZMO_COLOR);
+                }
+            }
+        }
+
+        // 3D games look at the play area through a perspective, so its bounds aren't in the world
+        if let (Some(play_area), CollisionPlane::Xy) = (play_area, *collision_plane) {
+            gizmos.rect_2d(Vec2::ZERO, 0.0, play_area.size(), PLAY_AREA_GIZMO_COLOR);
+        }
+    }
+}
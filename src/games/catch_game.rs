@@ -0,0 +1,445 @@
+use crate::{
+    collision::{Collider, CollisionStarted},
+    debug_overlay::DebugOverlayAppExt,
+    high_scores::ScoreOrder,
+    input::{action_axis, Action, AxisDirection, InputMap},
+    interpolation::Interpolated,
+    loading::{AssetManifest, UiFonts},
+    locale::{Locale, LocalizedText},
+    mini_game::{GameSystems, HostedGamePlugin, Localized, MiniGame, MiniGameInfo, MiniGameSet},
+    music::GameMusic,
+    play_area::{PlayArea, PlayAreaCamera},
+    pointer::Pointers,
+    result_screen::{GameFinished, Outcome},
+    resuming,
+    rng::GameRng,
+    sfx::SfxEvent,
+    theme::{Theme, ThemeColor, Themed},
+    tuning::{Tuning, TuningCheck, TuningPlugin},
+    AppState, GameplaySet, Velocity,
+};
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+use rand::Rng;
+use serde::Deserialize;
+use std::{f32::consts::PI, time::Duration};
+
+pub const WINDOW_SIZE: Vec2 = Vec2::new(800.0, 600.0);
+const GAME_ID: &str = "catch_game";
+
+const PLAYER_SIZE: Vec3 = Vec3::new(25.0, 25.0, 0.0);
+
+const OBSTACLE_SIZE: Vec3 = Vec3::new(20.0, 20.0, 0.0);
+
+const SCOREBOARD_FONT_SIZE: f32 = 30.0;
+const SCOREBOARD_TEXT_PADDING: f32 = 5.0;
+
+/// From `assets/tuning/catch_game.ron`.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, PartialEq, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct CatchGameTuning {
+    pub game_time_limit: f32,
+    pub player_speed: f32,
+    pub obstacle_spawn_interval: f32,
+    pub obstacle_speed: f32,
+}
+
+impl Tuning for CatchGameTuning {
+    const PATH: &'static str = "tuning/catch_game.ron";
+    const BUILT_IN: &'static str = include_str!("../../assets/tuning/catch_game.ron");
+
+    fn check(&self, check: &mut TuningCheck) {
+        check
+            .range("game_time_limit", self.game_time_limit, 1.0..=600.0)
+            .range("player_speed", self.player_speed, 1.0..=2000.0)
+            .range(
+                "obstacle_spawn_interval",
+                self.obstacle_spawn_interval,
+                0.05..=10.0,
+            )
+            .range("obstacle_speed", self.obstacle_speed, 1.0..=2000.0);
+    }
+}
+
+#[derive(Resource)]
+struct GameTimer(Timer);
+
+#[derive(Resource)]
+struct ObstacleSpawnTimer(Timer);
+
+#[derive(Resource, Component)]
+struct Scoreboard {
+    time: f32,
+    score: i32,
+}
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Obstacle {
+    point: i32,
+}
+
+pub struct CatchGamePlugin;
+
+impl MiniGame for CatchGamePlugin {
+    const INFO: MiniGameInfo = MiniGameInfo {
+        id: GAME_ID,
+        name: Localized {
+            en: "Catch Game",
+            ja: "キャッチゲーム",
+        },
+        description: Localized {
+            en: "Catch the falling objects for a high score.",
+            ja: "落ちてくるオブジェクトをキャッチして高得点を目指すゲーム。",
+        },
+        controls: Localized {
+            en: "Left and Right or A and D to move",
+            ja: "左右キーかAとDで移動",
+        },
+        window_size: WINDOW_SIZE,
+        colors: &[(ThemeColor::Score, Color::srgb(0.3, 0.3, 0.3))],
+        score_order: ScoreOrder::HigherIsBetter,
+        assets: AssetManifest::EMPTY,
+        music: GameMusic::DEFAULT,
+    };
+}
+
+impl Plugin for CatchGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(HostedGamePlugin::<Self>::default())
+            .add_plugins(TuningPlugin::<CatchGameTuning>::default())
+            .count_in_debug_overlay::<Obstacle>("Obstacle")
+            .add_systems(
+                OnEnter(Self::active()),
+                (insert_game_resources, setup_camera).in_set(MiniGameSet::Setup),
+            )
+            .add_systems(
+                OnExit(Self::active()),
+                remove_game_resources.in_set(MiniGameSet::Teardown),
+            )
+            .add_systems(
+                OnEnter(AppState::InGame),
+                setup.run_if(not(resuming)).in_set(GameSystems(GAME_ID)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    move_player,
+                    spawn_obstacle,
+                    collide_obstacle,
+                    cleanup_obstacle,
+                    update_game_timer,
+                )
+                    .chain()
+                    .in_set(GameplaySet)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(
+                Update,
+                (drag_player, update_scoreboard)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            );
+    }
+}
+
+// Kept across runs, only while this game is active
+fn insert_game_resources(mut commands: Commands, theme: Res<Theme>, tuning: Res<CatchGameTuning>) {
+    commands.insert_resource(Time::<Fixed>::from_seconds(1.0 / 60.0));
+    commands.insert_resource(ClearColor(theme.background));
+    commands.insert_resource(
+        InputMap::new(GAME_ID)
+            .bind(Action::MoveLeft, KeyCode::ArrowLeft)
+            .bind(Action::MoveLeft, KeyCode::KeyA)
+            .bind(Action::MoveRight, KeyCode::ArrowRight)
+            .bind(Action::MoveRight, KeyCode::KeyD)
+            .bind(
+                Action::MoveLeft,
+                (GamepadAxisType::LeftStickX, AxisDirection::Negative),
+            )
+            .bind(
+                Action::MoveRight,
+                (GamepadAxisType::LeftStickX, AxisDirection::Positive),
+            )
+            .bind(Action::MoveLeft, GamepadButtonType::DPadLeft)
+            .bind(Action::MoveRight, GamepadButtonType::DPadRight)
+            .load(),
+    );
+    commands.insert_resource(GameTimer(Timer::from_seconds(
+        tuning.game_time_limit,
+        TimerMode::Once,
+    )));
+    commands.insert_resource(ObstacleSpawnTimer(Timer::from_seconds(
+        tuning.obstacle_spawn_interval,
+        TimerMode::Repeating,
+    )));
+    commands.insert_resource(Scoreboard {
+        time: tuning.game_time_limit,
+        score: 0,
+    });
+}
+
+fn remove_game_resources(mut commands: Commands) {
+    commands.remove_resource::<GameTimer>();
+    commands.remove_resource::<ObstacleSpawnTimer>();
+    commands.remove_resource::<Scoreboard>();
+}
+
+fn setup_camera(mut commands: Commands) {
+    // Camera
+    commands.spawn((Camera2dBundle::default(), PlayAreaCamera));
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    fonts: Res<UiFonts>,
+    locale: Res<Locale>,
+    theme: Res<Theme>,
+    play_area: Res<PlayArea>,
+    tuning: Res<CatchGameTuning>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut game_timer: ResMut<GameTimer>,
+    mut spawn_timer: ResMut<ObstacleSpawnTimer>,
+) {
+    scoreboard.time = tuning.game_time_limit;
+    scoreboard.score = 0;
+    game_timer
+        .0
+        .set_duration(Duration::from_secs_f32(tuning.game_time_limit));
+    game_timer.0.reset();
+    spawn_timer.0.reset();
+
+    // Player
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes.add(RegularPolygon::new(1.0, 4)).into(),
+            material: materials.add(ColorMaterial::from(theme.player)),
+            transform: Transform {
+                translation: Vec3::new(0.0, play_area.bottom() + PLAYER_SIZE.y, 1.0),
+                scale: PLAYER_SIZE,
+                ..default()
+            },
+            ..default()
+        },
+        Themed(ThemeColor::Player),
+        Player,
+        Collider::aabb(PLAYER_SIZE.truncate()),
+        Interpolated::default(),
+    ));
+    // Scoreboard
+    commands.spawn((
+        TextBundle::from_sections([
+            TextSection::new(
+                locale.get("hud_time"),
+                TextStyle {
+                    font: locale.font(),
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: theme.text,
+                },
+            ),
+            TextSection::new(
+                tuning.game_time_limit.to_string(),
+                TextStyle {
+                    font: fonts.mono.clone(),
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: theme.score,
+                },
+            ),
+            TextSection::new(
+                locale.get("hud_then_score"),
+                TextStyle {
+                    font: locale.font(),
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: theme.text,
+                },
+            ),
+            TextSection::new(
+                "",
+                TextStyle {
+                    font: fonts.mono.clone(),
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: theme.score,
+                },
+            ),
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(SCOREBOARD_TEXT_PADDING),
+            left: Val::Px(SCOREBOARD_TEXT_PADDING),
+            ..default()
+        }),
+        LocalizedText::new("hud_time").with_section(2, "hud_then_score"),
+        Scoreboard {
+            time: tuning.game_time_limit,
+            score: 0,
+        },
+    ));
+}
+
+fn move_player(
+    action_axes: Res<Axis<Action>>,
+    mut player_query: Query<&mut Transform, With<Player>>,
+    play_area: Res<PlayArea>,
+    tuning: Res<CatchGameTuning>,
+    time_step: Res<Time<Fixed>>,
+) {
+    let mut player_transform = player_query.single_mut();
+    let direction = Vec2::new(
+        action_axis(&action_axes, Action::MoveLeft, Action::MoveRight),
+        0.0,
+    );
+
+    // Player x movement
+    let new_player_position_x = player_transform.translation.x
+        + direction.x * tuning.player_speed * time_step.delta().as_secs_f32();
+
+    player_transform.translation.x = clamp_player_x(&play_area, new_player_position_x);
+}
+
+// Dragging moves the player as far as the finger went, every frame rather than every tick
+fn drag_player(
+    pointers: Res<Pointers>,
+    play_area: Res<PlayArea>,
+    mut player_query: Query<&mut Transform, With<Player>>,
+) {
+    let drag = pointers.world_drag(&play_area);
+    if drag.x == 0.0 {
+        return;
+    }
+
+    let mut player_transform = player_query.single_mut();
+    player_transform.translation.x =
+        clamp_player_x(&play_area, player_transform.translation.x + drag.x);
+}
+
+fn clamp_player_x(play_area: &PlayArea, x: f32) -> f32 {
+    let x_bound = play_area.right() - PLAYER_SIZE.x;
+    x.clamp(-x_bound, x_bound)
+}
+
+fn spawn_obstacle(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    time: Res<Time>,
+    mut timer: ResMut<ObstacleSpawnTimer>,
+    mut rng: ResMut<GameRng>,
+    play_area: Res<PlayArea>,
+    theme: Res<Theme>,
+    tuning: Res<CatchGameTuning>,
+) {
+    // Saved while the game runs
+    let interval = Duration::from_secs_f32(tuning.obstacle_spawn_interval);
+    if timer.0.duration() != interval {
+        timer.0.set_duration(interval);
+    }
+
+    if timer.0.tick(time.delta()).just_finished() {
+        // Obstacle
+        let x_bound = play_area.right() - OBSTACLE_SIZE.x;
+        let obstacle_x = rng.gen_range(-x_bound..x_bound);
+        let obstacle_y = play_area.top() + OBSTACLE_SIZE.y;
+        let bool_obstacle = rng.gen_bool(1.0 / 2.0);
+        let obstacle_color = if bool_obstacle {
+            ThemeColor::Good
+        } else {
+            ThemeColor::Bad
+        };
+        let obstacle_point = if bool_obstacle { 1 } else { -1 };
+        // Not by colour alone: good drops are round, bad ones spikes pointing down
+        let (obstacle_mesh, obstacle_rotation) = if bool_obstacle {
+            (meshes.add(Circle::new(1.0)), Quat::IDENTITY)
+        } else {
+            (
+                meshes.add(RegularPolygon::new(1.0, 3)),
+                Quat::from_rotation_z(PI),
+            )
+        };
+
+        commands.spawn((
+            MaterialMesh2dBundle {
+                mesh: obstacle_mesh.into(),
+                material: materials.add(ColorMaterial::from(theme.color(obstacle_color))),
+                transform: Transform {
+                    translation: Vec3::new(obstacle_x, obstacle_y, 0.0),
+                    rotation: obstacle_rotation,
+                    scale: OBSTACLE_SIZE,
+                },
+                ..default()
+            },
+            Themed(obstacle_color),
+            Obstacle {
+                point: obstacle_point,
+            },
+            Collider::circle(OBSTACLE_SIZE.x / 2.0),
+            Velocity(Vec3::new(0.0, -tuning.obstacle_speed, 0.0)),
+            Interpolated::default(),
+        ));
+    }
+}
+
+fn collide_obstacle(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionStarted>,
+    player_query: Query<(), With<Player>>,
+    obstacle_query: Query<&Obstacle>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    for event in collision_events.read() {
+        if !player_query.contains(event.entity) {
+            continue;
+        }
+        if let Ok(obstacle) = obstacle_query.get(event.other) {
+            scoreboard.score += obstacle.point;
+            commands.entity(event.other).despawn();
+            sfx_events.send(if obstacle.point > 0 {
+                SfxEvent::Pickup
+            } else {
+                SfxEvent::Hit
+            });
+        }
+    }
+}
+
+fn cleanup_obstacle(
+    mut commands: Commands,
+    obstacle_query: Query<(Entity, &Transform), With<Obstacle>>,
+    play_area: Res<PlayArea>,
+) {
+    for (obstacle_entity, obstacle_transform) in obstacle_query.iter() {
+        let obstacle_pos = obstacle_transform.translation.truncate();
+
+        if !play_area.contains(obstacle_pos, OBSTACLE_SIZE.truncate()) {
+            commands.entity(obstacle_entity).despawn();
+        }
+    }
+}
+
+fn update_game_timer(
+    mut scoreboard: ResMut<Scoreboard>,
+    time: Res<Time>,
+    mut timer: ResMut<GameTimer>,
+    mut finished_events: EventWriter<GameFinished>,
+) {
+    scoreboard.time = timer.0.remaining_secs().round();
+
+    if timer.0.tick(time.delta()).just_finished() {
+        timer.0.reset();
+        finished_events
+            .send(GameFinished::new(Outcome::GameOver).with_score(scoreboard.score as f32));
+    }
+}
+
+fn update_scoreboard(
+    scoreboard: Res<Scoreboard>,
+    mut scoreboard_query: Query<&mut Text, With<Scoreboard>>,
+) {
+    let mut scoreboard_text = scoreboard_query.single_mut();
+    scoreboard_text.sections[1].value = scoreboard.time.to_string();
+    scoreboard_text.sections[3].value = scoreboard.score.to_string();
+}
@@ -0,0 +1,320 @@
+use crate::{
+    collision::{contact, Collider, ColliderShape, DEFAULT_LAYER},
+    debug_overlay::DebugOverlayAppExt,
+    high_scores::ScoreOrder,
+    input::{Action, InputMap},
+    interpolation::Interpolated,
+    loading::{AssetManifest, UiFonts},
+    locale::{Locale, LocalizedText},
+    mini_game::{GameSystems, HostedGamePlugin, Localized, MiniGame, MiniGameInfo, MiniGameSet},
+    music::GameMusic,
+    play_area::{PlayArea, PlayAreaCamera},
+    pointer::Pointers,
+    result_screen::{GameFinished, Outcome},
+    resuming,
+    rng::GameRng,
+    sfx::SfxEvent,
+    theme::{Theme, ThemeColor, Themed},
+    tuning::{Tuning, TuningCheck, TuningPlugin},
+    AppState, GameplaySet, Velocity,
+};
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle, time::Stopwatch, window::PrimaryWindow};
+use rand::distributions::{Distribution, Uniform};
+use serde::Deserialize;
+
+pub const WINDOW_SIZE: Vec2 = Vec2::new(1080.0, 720.0);
+const GAME_ID: &str = "click_game";
+
+const BALL_SIZE: Vec3 = Vec3::new(50.0, 50.0, 0.0);
+
+const CURSOR_COLLIDER: ColliderShape = ColliderShape::Circle(15.0);
+
+const SCOREBOARD_FONT_SIZE: f32 = 40.0;
+const SCOREBOARD_TEXT_PADDING: Val = Val::Px(5.0);
+
+/// From `assets/tuning/click_game.ron`.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, PartialEq, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ClickGameTuning {
+    pub ball_count: usize,
+    pub ball_speed: f32,
+}
+
+impl Tuning for ClickGameTuning {
+    const PATH: &'static str = "tuning/click_game.ron";
+    const BUILT_IN: &'static str = include_str!("../../assets/tuning/click_game.ron");
+
+    fn check(&self, check: &mut TuningCheck) {
+        check.range("ball_count", self.ball_count, 1..=500).range(
+            "ball_speed",
+            self.ball_speed,
+            1.0..=3000.0,
+        );
+    }
+}
+
+#[derive(Resource, Component)]
+struct Scoreboard {
+    ball_count: usize,
+}
+
+#[derive(Resource, Default)]
+struct ClearTime(Stopwatch);
+
+pub struct ClickGamePlugin;
+
+impl MiniGame for ClickGamePlugin {
+    const INFO: MiniGameInfo = MiniGameInfo {
+        id: GAME_ID,
+        name: Localized {
+            en: "Click Game",
+            ja: "クリックゲーム",
+        },
+        description: Localized {
+            en: "Click the balls bouncing around the screen until none are left.",
+            ja: "画面内を跳ね返っているボールをクリックして、ボールを全消しを目指すゲーム。",
+        },
+        controls: Localized {
+            en: "Click or tap a ball to pop it",
+            ja: "ボールをクリックかタップして消す",
+        },
+        window_size: WINDOW_SIZE,
+        colors: &[
+            (ThemeColor::Background, Color::WHITE),
+            (ThemeColor::Text, Color::srgb(0.5, 0.5, 1.0)),
+            (ThemeColor::Score, Color::srgb(1.0, 0.5, 0.5)),
+        ],
+        score_order: ScoreOrder::LowerIsBetter,
+        assets: AssetManifest::EMPTY,
+        music: GameMusic::DEFAULT,
+    };
+}
+
+impl Plugin for ClickGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(HostedGamePlugin::<Self>::default())
+            .add_plugins(TuningPlugin::<ClickGameTuning>::default())
+            .count_in_debug_overlay::<Ball>("Ball")
+            .add_systems(
+                OnEnter(Self::active()),
+                (insert_game_resources, setup_camera).in_set(MiniGameSet::Setup),
+            )
+            .add_systems(
+                OnExit(Self::active()),
+                remove_game_resources.in_set(MiniGameSet::Teardown),
+            )
+            .add_systems(
+                OnEnter(AppState::InGame),
+                setup.run_if(not(resuming)).in_set(GameSystems(GAME_ID)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (check_for_collisions, tick_clear_time)
+                    .chain()
+                    .in_set(GameplaySet)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            )
+            // Pops right where the pointer is on screen, every frame rather than every tick
+            .add_systems(
+                Update,
+                (pop_balls, update_scoreboard)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            );
+    }
+}
+
+// Kept across runs, only while this game is active
+fn insert_game_resources(mut commands: Commands, theme: Res<Theme>, tuning: Res<ClickGameTuning>) {
+    commands.insert_resource(Time::<Fixed>::from_seconds(1.0 / 60.0));
+    commands.insert_resource(ClearColor(theme.background));
+    // Clicks and taps are read from `Pointers`, `Fire` pops at the cursor
+    commands.insert_resource(
+        InputMap::new(GAME_ID)
+            .bind(Action::Fire, GamepadButtonType::South)
+            .load(),
+    );
+    commands.init_resource::<ClearTime>();
+    commands.insert_resource(Scoreboard {
+        ball_count: tuning.ball_count,
+    });
+}
+
+fn remove_game_resources(mut commands: Commands) {
+    commands.remove_resource::<ClearTime>();
+    commands.remove_resource::<Scoreboard>();
+}
+
+#[derive(Component)]
+struct Ball;
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn((Camera2dBundle::default(), PlayAreaCamera));
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    fonts: Res<UiFonts>,
+    locale: Res<Locale>,
+    theme: Res<Theme>,
+    play_area: Res<PlayArea>,
+    mut rng: ResMut<GameRng>,
+    tuning: Res<ClickGameTuning>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut clear_time: ResMut<ClearTime>,
+) {
+    scoreboard.ball_count = tuning.ball_count;
+    clear_time.0.reset();
+    // Balls
+    let die_width = Uniform::from(play_area.left() + BALL_SIZE.x..play_area.right() - BALL_SIZE.x);
+    let die_height = Uniform::from(play_area.bottom() + BALL_SIZE.y..play_area.top() - BALL_SIZE.y);
+    let die_velocity = Uniform::from(-0.5..0.5);
+
+    for _ in 0..tuning.ball_count {
+        let ball_pos_x = die_width.sample(&mut *rng);
+        let ball_pos_y = die_height.sample(&mut *rng);
+        let ball_velocity_x = die_velocity.sample(&mut *rng);
+        let ball_velocity_y = die_velocity.sample(&mut *rng);
+
+        commands.spawn((
+            MaterialMesh2dBundle {
+                mesh: meshes.add(Circle::default()).into(),
+                material: materials.add(ColorMaterial::from(theme.enemy)),
+                transform: Transform::from_translation(Vec3::new(ball_pos_x, ball_pos_y, 1.0))
+                    .with_scale(BALL_SIZE),
+                ..default()
+            },
+            Themed(ThemeColor::Enemy),
+            Ball,
+            // Balls pass through each other, only the cursor is tested against them
+            Collider::circle(BALL_SIZE.x / 2.0).with_layers(DEFAULT_LAYER, 0),
+            Velocity(Vec3::new(ball_velocity_x, ball_velocity_y, 0.0) * tuning.ball_speed),
+            Interpolated::default(),
+        ));
+    }
+    // Scoreboard
+    commands.spawn((
+        TextBundle::from_sections([
+            TextSection::new(
+                locale.get("hud_ball_count"),
+                TextStyle {
+                    font: locale.font(),
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: theme.text,
+                },
+            ),
+            TextSection::new(
+                tuning.ball_count.to_string(),
+                TextStyle {
+                    font: fonts.mono.clone(),
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: theme.score,
+                },
+            ),
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: SCOREBOARD_TEXT_PADDING,
+            left: SCOREBOARD_TEXT_PADDING,
+            ..default()
+        }),
+        LocalizedText::new("hud_ball_count"),
+        Scoreboard { ball_count: 0 },
+    ));
+}
+
+fn pop_balls(
+    mut commands: Commands,
+    mut scoreboard: ResMut<Scoreboard>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    play_area: Res<PlayArea>,
+    pointers: Res<Pointers>,
+    action_input: Res<ButtonInput<Action>>,
+    balls_query: Query<(Entity, &Transform, &Collider), With<Ball>>,
+    clear_time: Res<ClearTime>,
+    mut finished_events: EventWriter<GameFinished>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    let window = window_query.single();
+
+    // Every finger touching down pops at once
+    let mut pop_positions: Vec<Vec2> = pointers
+        .iter_just_pressed()
+        .map(|pointer| pointer.world_position(&play_area))
+        .collect();
+    if action_input.just_pressed(Action::Fire) {
+        if let Some(cursor_position) = window.cursor_position() {
+            pop_positions.push(play_area.window_to_world(cursor_position));
+        }
+    }
+
+    if pop_positions.is_empty() {
+        return;
+    }
+
+    for (ball_entity, ball_transform, ball_collider) in balls_query.iter() {
+        let ball_pos = ball_transform.translation.truncate();
+        let popped = pop_positions.iter().any(|&pop_position| {
+            contact(
+                pop_position,
+                &CURSOR_COLLIDER,
+                ball_pos,
+                &ball_collider.shape,
+            )
+            .is_some()
+        });
+
+        if popped {
+            scoreboard.ball_count -= 1;
+            commands.entity(ball_entity).despawn();
+            sfx_events.send(SfxEvent::Pop);
+        }
+    }
+
+    if scoreboard.ball_count == 0 {
+        // Ranked by how fast every ball was cleared
+        let clear_time = (clear_time.0.elapsed_secs() * 100.0).round() / 100.0;
+        finished_events.send(GameFinished::new(Outcome::GameClear).with_score(clear_time));
+    }
+}
+
+fn tick_clear_time(mut clear_time: ResMut<ClearTime>, time: Res<Time>) {
+    clear_time.0.tick(time.delta());
+}
+
+fn check_for_collisions(
+    play_area: Res<PlayArea>,
+    mut balls_query: Query<(&mut Velocity, &Transform), With<Ball>>,
+) {
+    for (mut ball_velocity, ball_transform) in balls_query.iter_mut() {
+        let ball_size = ball_transform.scale.truncate();
+
+        let left_window_collision =
+            play_area.right() < ball_transform.translation.x + ball_size.x / 2.0;
+        let right_window_collision =
+            play_area.left() > ball_transform.translation.x - ball_size.x / 2.0;
+        let top_window_collision =
+            play_area.top() < ball_transform.translation.y + ball_size.y / 2.0;
+        let bottom_window_collision =
+            play_area.bottom() > ball_transform.translation.y - ball_size.y / 2.0;
+
+        if left_window_collision || right_window_collision {
+            ball_velocity.x = -ball_velocity.x;
+        }
+
+        if top_window_collision || bottom_window_collision {
+            ball_velocity.y = -ball_velocity.y;
+        }
+    }
+}
+
+fn update_scoreboard(
+    scoreboard: Res<Scoreboard>,
+    mut scoreboard_query: Query<&mut Text, With<Scoreboard>>,
+) {
+    let mut text = scoreboard_query.single_mut();
+    text.sections[1].value = scoreboard.ball_count.to_string();
+}
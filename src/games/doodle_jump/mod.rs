@@ -0,0 +1,150 @@
+use crate::{
+    debug_overlay::DebugOverlayAppExt,
+    high_scores::ScoreOrder,
+    loading::AssetManifest,
+    mini_game::{HostedGamePlugin, Localized, MiniGame, MiniGameInfo, MiniGameSet},
+    music::GameMusic,
+    play_area::{PlayArea, PlayAreaCamera},
+    theme::{Theme, ThemeColor, Themed},
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub const WINDOW_SIZE: Vec2 = Vec2::new(400.0, 600.0);
+const GAME_ID: &str = "doodle_jump";
+
+const TILE_SIZE: f32 = 40.0;
+
+pub struct DoodleJumpPlugin;
+
+// Only its first stage is laid out so far, so the arcade doesn't list it yet
+impl MiniGame for DoodleJumpPlugin {
+    const INFO: MiniGameInfo = MiniGameInfo {
+        id: GAME_ID,
+        name: Localized {
+            en: "Doodle Jump",
+            ja: "ドゥードゥルジャンプ",
+        },
+        description: Localized {
+            en: "Climb the platforms as high as you can.",
+            ja: "足場を登ってできるだけ高くを目指すゲーム。",
+        },
+        controls: Localized {
+            en: "Esc to pause",
+            ja: "Escキーで一時停止",
+        },
+        window_size: WINDOW_SIZE,
+        colors: &[],
+        score_order: ScoreOrder::HigherIsBetter,
+        assets: AssetManifest::EMPTY,
+        music: GameMusic::DEFAULT,
+    };
+}
+
+impl Plugin for DoodleJumpPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(HostedGamePlugin::<Self>::default())
+            .count_in_debug_overlay::<TileGround>("TileGround")
+            .add_systems(
+                OnEnter(Self::active()),
+                (insert_game_resources, setup_camera, setup_tilemap).in_set(MiniGameSet::Setup),
+            );
+    }
+}
+
+// Kept across runs, only while this game is active
+fn insert_game_resources(mut commands: Commands, theme: Res<Theme>) {
+    commands.insert_resource(Time::<Fixed>::from_seconds(1.0 / 60.0));
+    commands.insert_resource(ClearColor(theme.background));
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct TileMap {
+    map: Vec<Vec<u32>>,
+}
+
+#[derive(Component)]
+struct TileGround;
+
+#[derive(Component)]
+struct TileGoal;
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn((Camera2dBundle::default(), PlayAreaCamera));
+}
+
+fn setup_tilemap(mut commands: Commands, play_area: Res<PlayArea>, theme: Res<Theme>) {
+    let tilemap: TileMap = serde_json::from_slice(include_bytes!("stage_1.json")).unwrap();
+    let window_top_left = play_area.top_left();
+
+    for (y, row) in tilemap.map.iter().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            if cell > &0 {
+                let tile_y = window_top_left.y - TILE_SIZE * (y as f32 + 0.5);
+                let tile_x = window_top_left.x + TILE_SIZE * (x as f32 + 0.5);
+
+                let tile_ground = (
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: theme.ground,
+                            ..default()
+                        },
+                        transform: Transform {
+                            translation: Vec3::new(tile_x, tile_y, 0.0),
+                            scale: Vec3::splat(TILE_SIZE),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Themed(ThemeColor::Ground),
+                    TileGround,
+                );
+                let tile_goal = (
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: theme.goal,
+                            ..default()
+                        },
+                        transform: Transform {
+                            translation: Vec3::new(tile_x, tile_y, 0.0),
+                            scale: Vec3::splat(TILE_SIZE),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Themed(ThemeColor::Goal),
+                    TileGoal,
+                );
+
+                match cell {
+                    1 => {
+                        commands.spawn(tile_ground);
+                    }
+                    // Hollow, so it stands out from the ground by more than its colour
+                    2 => {
+                        commands.spawn(tile_goal).with_children(|parent| {
+                            parent.spawn((
+                                SpriteBundle {
+                                    sprite: Sprite {
+                                        color: theme.background,
+                                        ..default()
+                                    },
+                                    transform: Transform {
+                                        translation: Vec3::new(0.0, 0.0, 0.1),
+                                        scale: Vec3::splat(0.5),
+                                        ..default()
+                                    },
+                                    ..default()
+                                },
+                                Themed(ThemeColor::Background),
+                            ));
+                        });
+                    }
+                    _ => {
+                        commands.spawn(SpriteBundle::default());
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,426 @@
+use crate::{
+    collision::{Collider, Contacts},
+    debug_overlay::DebugOverlayAppExt,
+    high_scores::ScoreOrder,
+    input::{Action, InputBinding, InputMap},
+    interpolation::Interpolated,
+    loading::{AssetManifest, UiFonts},
+    locale::{Locale, LocalizedText},
+    mini_game::{GameSystems, HostedGamePlugin, Localized, MiniGame, MiniGameInfo, MiniGameSet},
+    music::GameMusic,
+    play_area::{PlayArea, PlayAreaCamera},
+    result_screen::{GameFinished, Outcome},
+    resuming,
+    sfx::SfxEvent,
+    theme::{Theme, ThemeColor, Themed},
+    tuning::{Tuning, TuningCheck, TuningPlugin},
+    AppState, GameplaySet, Velocity,
+};
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+use serde::Deserialize;
+
+pub const WINDOW_SIZE: Vec2 = Vec2::new(700.0, 700.0);
+const GAME_ID: &str = "flappy_bird";
+
+const PLAYER_SIZE: Vec3 = Vec3::new(25.0, 25.0, 0.0);
+
+const OBSTACLE_WIDTH: f32 = 50.0;
+// What is left of each of the pair however wide the gap is tuned
+const OBSTACLE_MIN_HEIGHT: f32 = 25.0;
+
+const SCOREBOARD_FONT_SIZE: f32 = 24.0;
+const SCOREBOARD_TEXT_PADDING: Val = Val::Px(5.0);
+
+/// From `assets/tuning/flappy_bird.ron`. The speeds are per second, tuned to match the original
+/// per-frame values at 60 fps.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, PartialEq, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FlappyBirdTuning {
+    pub player_jump: f32,
+    pub player_jump_deceleration: f32,
+    pub player_fall_speed: f32,
+    pub player_collide_cooldown: f32,
+    pub player_life: usize,
+    pub obstacle_space: f32,
+    pub obstacle_speed: f32,
+}
+
+impl Tuning for FlappyBirdTuning {
+    const PATH: &'static str = "tuning/flappy_bird.ron";
+    const BUILT_IN: &'static str = include_str!("../../assets/tuning/flappy_bird.ron");
+
+    fn check(&self, check: &mut TuningCheck) {
+        check
+            .range("player_jump", self.player_jump, 1.0..=10000.0)
+            .range(
+                "player_jump_deceleration",
+                self.player_jump_deceleration,
+                1.0..=100000.0,
+            )
+            .range("player_fall_speed", self.player_fall_speed, 0.0..=2000.0)
+            .range(
+                "player_collide_cooldown",
+                self.player_collide_cooldown,
+                0.0..=10.0,
+            )
+            .range("player_life", self.player_life, 1..=99)
+            .range(
+                "obstacle_space",
+                self.obstacle_space,
+                PLAYER_SIZE.y..=10000.0,
+            )
+            .range("obstacle_speed", self.obstacle_speed, 1.0..=2000.0);
+    }
+}
+
+impl FlappyBirdTuning {
+    // Each of the pair reaches from an edge of the play area to the gap
+    fn obstacle_size(&self, play_area: &PlayArea) -> Vec3 {
+        let height = play_area.size().y;
+        let space = self.obstacle_space.min(height - OBSTACLE_MIN_HEIGHT * 2.0);
+        Vec3::new(OBSTACLE_WIDTH, (height - space) / 2.0, 0.0)
+    }
+}
+
+#[derive(Resource)]
+struct ObstacleSpawnTimer(Timer);
+
+#[derive(Resource, Component)]
+struct Scoreboard {
+    score: f32,
+    life: usize,
+}
+
+pub struct FlappyBirdPlugin;
+
+impl MiniGame for FlappyBirdPlugin {
+    const INFO: MiniGameInfo = MiniGameInfo {
+        id: GAME_ID,
+        name: Localized {
+            en: "Flappy Bird",
+            ja: "フラッピーバード",
+        },
+        description: Localized {
+            en: "Keep the bird flying past the obstacles for a high score.",
+            ja: "プレイヤー（鳥）を操作して、障害物を避けて飛ばし続けて高得点を目指すゲーム。",
+        },
+        controls: Localized {
+            en: "Space, click or tap to flap",
+            ja: "スペースキーかクリック、タップで羽ばたく",
+        },
+        window_size: WINDOW_SIZE,
+        colors: &[],
+        score_order: ScoreOrder::HigherIsBetter,
+        assets: AssetManifest::EMPTY,
+        music: GameMusic::DEFAULT,
+    };
+}
+
+impl Plugin for FlappyBirdPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(HostedGamePlugin::<Self>::default())
+            .add_plugins(TuningPlugin::<FlappyBirdTuning>::default())
+            .count_in_debug_overlay::<Obstacle>("Obstacle")
+            .add_systems(
+                OnEnter(Self::active()),
+                (insert_game_resources, setup_camera).in_set(MiniGameSet::Setup),
+            )
+            .add_systems(
+                OnExit(Self::active()),
+                remove_game_resources.in_set(MiniGameSet::Teardown),
+            )
+            .add_systems(
+                OnEnter(AppState::InGame),
+                setup.run_if(not(resuming)).in_set(GameSystems(GAME_ID)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    jump_player,
+                    player_gravity,
+                    despawn_player,
+                    spawn_obstacles,
+                    despawn_obstacles,
+                    obstacle_collision,
+                    pass_obstacle,
+                )
+                    .chain()
+                    .in_set(GameplaySet)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(
+                Update,
+                update_scoreboard
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            );
+    }
+}
+
+// Kept across runs, only while this game is active
+fn insert_game_resources(mut commands: Commands, theme: Res<Theme>, tuning: Res<FlappyBirdTuning>) {
+    commands.insert_resource(Time::<Fixed>::from_seconds(1.0 / 60.0));
+    commands.insert_resource(ClearColor(theme.background));
+    commands.insert_resource(
+        InputMap::new(GAME_ID)
+            .bind(Action::Jump, KeyCode::Space)
+            .bind(Action::Jump, GamepadButtonType::South)
+            .bind(Action::Jump, InputBinding::Pointer)
+            .load(),
+    );
+    commands.insert_resource(ObstacleSpawnTimer(Timer::from_seconds(
+        2.0,
+        TimerMode::Repeating,
+    )));
+    commands.insert_resource(Scoreboard {
+        score: -1.0,
+        life: tuning.player_life,
+    });
+}
+
+fn remove_game_resources(mut commands: Commands) {
+    commands.remove_resource::<ObstacleSpawnTimer>();
+    commands.remove_resource::<Scoreboard>();
+}
+
+#[derive(Component)]
+struct Player {
+    vel_y: f32,
+    collide_cooldown: Timer,
+    life: usize,
+}
+
+#[derive(Component)]
+struct Obstacle {
+    is_passed: bool,
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn((Camera2dBundle::default(), PlayAreaCamera));
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    fonts: Res<UiFonts>,
+    locale: Res<Locale>,
+    theme: Res<Theme>,
+    tuning: Res<FlappyBirdTuning>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut timer: ResMut<ObstacleSpawnTimer>,
+) {
+    scoreboard.score = -1.0;
+    scoreboard.life = tuning.player_life;
+    timer.0.reset();
+    // Player
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes.add(RegularPolygon::new(1.0, 4)).into(),
+            material: materials.add(ColorMaterial::from(theme.player)),
+            transform: Transform {
+                translation: Vec3::new(0.0, 0.0, 1.0),
+                scale: PLAYER_SIZE,
+                ..default()
+            },
+            ..default()
+        },
+        Themed(ThemeColor::Player),
+        Player {
+            vel_y: 0.0,
+            collide_cooldown: Timer::from_seconds(tuning.player_collide_cooldown, TimerMode::Once),
+            life: tuning.player_life,
+        },
+        Collider::aabb(PLAYER_SIZE.truncate()),
+        Interpolated::default(),
+    ));
+    // Scoreboard
+    let font_bold: Handle<Font> = locale.font();
+    let font_medium: Handle<Font> = fonts.mono.clone();
+    let text_closure = |font: Handle<Font>, text: &str, color: Color| -> TextSection {
+        let style = TextStyle {
+            font,
+            font_size: SCOREBOARD_FONT_SIZE,
+            color,
+        };
+        TextSection::new(text, style)
+    };
+    commands.spawn((
+        TextBundle::from_sections([
+            text_closure(font_bold.clone(), locale.get("hud_score"), theme.text),
+            text_closure(font_medium.clone(), "", theme.score),
+            text_closure(font_bold.clone(), locale.get("hud_then_life"), theme.text),
+            text_closure(font_medium.clone(), "", theme.score),
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: SCOREBOARD_TEXT_PADDING,
+            left: SCOREBOARD_TEXT_PADDING,
+            ..default()
+        }),
+        LocalizedText::new("hud_score").with_section(2, "hud_then_life"),
+        Scoreboard {
+            score: -1.0,
+            life: tuning.player_life,
+        },
+    ));
+}
+
+fn jump_player(
+    action_input: Res<ButtonInput<Action>>,
+    mut player_query: Query<(&mut Player, &mut Transform), With<Player>>,
+    tuning: Res<FlappyBirdTuning>,
+    time: Res<Time>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    if let Ok((mut player, mut player_transform)) = player_query.get_single_mut() {
+        if action_input.just_pressed(Action::Jump) {
+            player.vel_y += tuning.player_jump;
+            sfx_events.send(SfxEvent::Jump);
+        }
+
+        if player.vel_y > 0.0 {
+            player.vel_y -= tuning.player_jump_deceleration * time.delta_seconds();
+            player_transform.translation.y += player.vel_y * time.delta_seconds();
+        }
+    }
+}
+
+fn player_gravity(
+    mut player_query: Query<&mut Transform, With<Player>>,
+    tuning: Res<FlappyBirdTuning>,
+    time: Res<Time>,
+) {
+    if let Ok(mut player_transform) = player_query.get_single_mut() {
+        player_transform.translation.y -= tuning.player_fall_speed * time.delta_seconds();
+    }
+}
+
+fn despawn_player(
+    mut commands: Commands,
+    player_query: Query<(Entity, &Player), With<Player>>,
+    scoreboard: Res<Scoreboard>,
+    mut finished_events: EventWriter<GameFinished>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    if let Ok((player_entity, player)) = &player_query.get_single() {
+        if player.life == 0 {
+            commands.entity(*player_entity).despawn();
+            sfx_events.send(SfxEvent::Explode);
+            finished_events
+                .send(GameFinished::new(Outcome::GameOver).with_score(scoreboard.score.max(0.0)));
+        }
+    }
+}
+
+fn spawn_obstacles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut timer: ResMut<ObstacleSpawnTimer>,
+    play_area: Res<PlayArea>,
+    theme: Res<Theme>,
+    tuning: Res<FlappyBirdTuning>,
+) {
+    if !timer.0.tick(time.delta()).finished() {
+        return;
+    }
+
+    let obstacle_size = tuning.obstacle_size(&play_area);
+    let x = play_area.right() + obstacle_size.x / 2.0;
+    let mut y = play_area.top() - obstacle_size.y / 2.0;
+
+    for i in 0..2 {
+        if i == 1 {
+            y = -y;
+        }
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: theme.bad,
+                    custom_size: Some(Vec2::new(1.0, 1.0)),
+                    ..default()
+                },
+                transform: Transform {
+                    translation: Vec2::new(x, y).extend(0.0),
+                    scale: obstacle_size,
+                    ..default()
+                },
+                ..default()
+            },
+            Themed(ThemeColor::Bad),
+            Obstacle { is_passed: false },
+            Collider::aabb(obstacle_size.truncate()),
+            Velocity(Vec3::new(-tuning.obstacle_speed, 0., 0.)),
+            Interpolated::default(),
+        ));
+    }
+}
+
+fn despawn_obstacles(
+    mut commands: Commands,
+    mut obstacle_query: Query<(Entity, &Transform), With<Obstacle>>,
+    play_area: Res<PlayArea>,
+) {
+    for (obstacle_entity, obstacle_transform) in &mut obstacle_query {
+        if obstacle_transform.translation.x < play_area.left() - OBSTACLE_WIDTH / 2.0 {
+            commands.entity(obstacle_entity).despawn();
+        }
+    }
+}
+
+fn obstacle_collision(
+    mut player_query: Query<(Entity, &mut Player)>,
+    obstacle_query: Query<(), With<Obstacle>>,
+    contacts: Res<Contacts>,
+    time: Res<Time>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    if let Ok((player_entity, mut player)) = player_query.get_single_mut() {
+        if !player.collide_cooldown.tick(time.delta()).finished() {
+            return;
+        }
+
+        // Keep hurting the player while it stays inside an obstacle
+        let collision = contacts
+            .of(player_entity)
+            .any(|(other, _)| obstacle_query.contains(other));
+
+        if collision {
+            player.collide_cooldown.reset();
+            player.life -= 1;
+            scoreboard.life -= 1;
+            sfx_events.send(SfxEvent::Hit);
+        }
+    }
+}
+
+fn pass_obstacle(
+    player_query: Query<&Transform, With<Player>>,
+    mut obstacle_query: Query<(&mut Obstacle, &Transform), With<Obstacle>>,
+    mut scoreboard: ResMut<Scoreboard>,
+) {
+    if let Ok(player_transform) = player_query.get_single() {
+        for (mut obstacle, obstacle_transform) in &mut obstacle_query {
+            if obstacle.is_passed {
+                continue;
+            }
+
+            if player_transform.translation.x < obstacle_transform.translation.x {
+                obstacle.is_passed = true;
+                scoreboard.score += 0.5;
+            }
+        }
+    }
+}
+
+fn update_scoreboard(
+    scoreboard: Res<Scoreboard>,
+    mut scoreboard_query: Query<&mut Text, With<Scoreboard>>,
+) {
+    let mut text = scoreboard_query.single_mut();
+    text.sections[1].value = scoreboard.score.to_string();
+    text.sections[3].value = scoreboard.life.to_string();
+}
@@ -0,0 +1,505 @@
+use crate::{
+    collision::{Collider, CollisionPlane, CollisionStarted},
+    debug_overlay::DebugOverlayAppExt,
+    high_scores::ScoreOrder,
+    input::{Action, AxisDirection, InputMap},
+    interpolation::Interpolated,
+    loading::{AssetManifest, GameModels, UiFonts},
+    locale::{Locale, LocalizedText},
+    mini_game::{GameSystems, HostedGamePlugin, Localized, MiniGame, MiniGameInfo, MiniGameSet},
+    music::GameMusic,
+    play_area::PlayAreaCamera,
+    result_screen::{GameFinished, Outcome},
+    resuming,
+    rng::GameRng,
+    sfx::SfxEvent,
+    spatial_hash::SpatialHash,
+    theme::{Theme, ThemeColor, Themed},
+    tuning::{Tuning, TuningCheck, TuningPlugin},
+    AppState, GameplaySet, Velocity,
+};
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+use std::{f32::consts::PI, time::Duration};
+
+pub const WINDOW_SIZE: Vec2 = Vec2::new(800.0, 600.0);
+const GAME_ID: &str = "frogger";
+
+const BOARD_SIZE_I: usize = 12;
+const BOARD_SIZE_J: usize = 8;
+
+const CAMERA_DISTANCE: Vec3 = Vec3::new(-2.8, 3.0, 3.5);
+
+const PLAYER_INITIAL_POSITION: Vec3 = Vec3::new(0.0, 0.0, BOARD_SIZE_J as f32 / 2.0);
+// Narrow along x so only obstacles on the player's own row can hit it
+const PLAYER_COLLIDER_SIZE: Vec2 = Vec2::new(0.5, 1.0);
+
+const OBSTACLE_SIZE: f32 = 0.8;
+
+const TILE_MODEL: &str = "models/Frogger/tile.glb#Scene0";
+const PLAYER_MODEL: &str = "models/Frogger/gekota.glb#Scene0";
+
+const SCOREBOARD_FONT_SIZE: f32 = 40.0;
+const SCOREBOARD_TEXT_PADDING: Val = Val::Px(5.0);
+
+/// From `assets/tuning/frogger.ron`.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, PartialEq, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FroggerTuning {
+    pub game_time_limit: f32,
+    pub camera_speed: f32,
+}
+
+impl Tuning for FroggerTuning {
+    const PATH: &'static str = "tuning/frogger.ron";
+    const BUILT_IN: &'static str = include_str!("../../assets/tuning/frogger.ron");
+
+    fn check(&self, check: &mut TuningCheck) {
+        check
+            .range("game_time_limit", self.game_time_limit, 1.0..=600.0)
+            // At 60 it would reach the frog in a single frame at 60 fps, past it overshoot
+            .range("camera_speed", self.camera_speed, 0.1..=30.0);
+    }
+}
+
+pub struct FroggerPlugin;
+
+impl MiniGame for FroggerPlugin {
+    const INFO: MiniGameInfo = MiniGameInfo {
+        id: GAME_ID,
+        name: Localized {
+            en: "Frogger",
+            ja: "フロッガー",
+        },
+        description: Localized {
+            en: "Get the frog past the obstacles to the goal on the far bank.",
+            ja: "プレイヤー（カエル）を障害物を避けつつ対岸にあるゴールを目指すゲーム。",
+        },
+        controls: Localized {
+            en: "Arrows or WASD to hop",
+            ja: "矢印キーかWASDで跳ねる",
+        },
+        window_size: WINDOW_SIZE,
+        colors: &[],
+        score_order: ScoreOrder::HigherIsBetter,
+        assets: AssetManifest {
+            models: &[TILE_MODEL, PLAYER_MODEL],
+        },
+        music: GameMusic::DEFAULT,
+    };
+}
+
+impl Plugin for FroggerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(HostedGamePlugin::<Self>::default())
+            .add_plugins(TuningPlugin::<FroggerTuning>::default())
+            .count_in_debug_overlay::<Obstacle>("Obstacle")
+            .add_systems(
+                OnEnter(Self::active()),
+                (insert_game_resources, setup_camera).in_set(MiniGameSet::Setup),
+            )
+            .add_systems(
+                OnExit(Self::active()),
+                remove_game_resources.in_set(MiniGameSet::Teardown),
+            )
+            .add_systems(
+                OnEnter(AppState::InGame),
+                setup.run_if(not(resuming)).in_set(GameSystems(GAME_ID)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    check_for_collision,
+                    move_player,
+                    move_obstacle,
+                    goal_player,
+                    update_game_timer,
+                )
+                    .chain()
+                    .in_set(GameplaySet)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(
+                Update,
+                (focus_camera, update_scoreboard)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            );
+    }
+}
+
+// Kept across runs, only while this game is active
+fn insert_game_resources(mut commands: Commands, theme: Res<Theme>, tuning: Res<FroggerTuning>) {
+    commands.insert_resource(Time::<Fixed>::from_seconds(1.0 / 60.0));
+    commands.insert_resource(ClearColor(theme.dark_background));
+    commands.insert_resource(
+        InputMap::new(GAME_ID)
+            .bind(Action::MoveUp, KeyCode::KeyW)
+            .bind(Action::MoveUp, KeyCode::ArrowUp)
+            .bind(Action::MoveDown, KeyCode::KeyS)
+            .bind(Action::MoveDown, KeyCode::ArrowDown)
+            .bind(Action::MoveRight, KeyCode::KeyD)
+            .bind(Action::MoveRight, KeyCode::ArrowRight)
+            .bind(Action::MoveLeft, KeyCode::KeyA)
+            .bind(Action::MoveLeft, KeyCode::ArrowLeft)
+            .bind(
+                Action::MoveUp,
+                (GamepadAxisType::LeftStickY, AxisDirection::Positive),
+            )
+            .bind(
+                Action::MoveDown,
+                (GamepadAxisType::LeftStickY, AxisDirection::Negative),
+            )
+            .bind(
+                Action::MoveRight,
+                (GamepadAxisType::LeftStickX, AxisDirection::Positive),
+            )
+            .bind(
+                Action::MoveLeft,
+                (GamepadAxisType::LeftStickX, AxisDirection::Negative),
+            )
+            .bind(Action::MoveUp, GamepadButtonType::DPadUp)
+            .bind(Action::MoveDown, GamepadButtonType::DPadDown)
+            .bind(Action::MoveRight, GamepadButtonType::DPadRight)
+            .bind(Action::MoveLeft, GamepadButtonType::DPadLeft)
+            .load(),
+    );
+    commands.insert_resource(CollisionPlane::Xz);
+    commands.insert_resource(SpatialHash::new(2.0));
+    commands.insert_resource(GameTimer(Timer::from_seconds(
+        tuning.game_time_limit,
+        TimerMode::Once,
+    )));
+    commands.insert_resource(Scoreboard {
+        time: tuning.game_time_limit,
+        score: 0,
+    });
+}
+
+fn remove_game_resources(mut commands: Commands) {
+    commands.remove_resource::<GameTimer>();
+    commands.remove_resource::<Scoreboard>();
+}
+
+#[derive(Component)]
+struct Camera {
+    looking_at: Vec3,
+}
+
+#[derive(Component)]
+struct Player {
+    i: f32,
+    j: f32,
+    move_cooldown: Timer,
+}
+
+#[derive(Component)]
+struct Obstacle;
+
+#[derive(Resource)]
+struct GameTimer(Timer);
+
+#[derive(Resource, Component)]
+struct Scoreboard {
+    time: f32,
+    score: isize,
+}
+
+fn initial_camera_transform() -> Transform {
+    Transform::from_translation(PLAYER_INITIAL_POSITION + CAMERA_DISTANCE)
+        .looking_at(PLAYER_INITIAL_POSITION, Vec3::Y)
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn((
+        Camera3dBundle {
+            transform: initial_camera_transform(),
+            ..default()
+        },
+        Camera {
+            looking_at: PLAYER_INITIAL_POSITION,
+        },
+        PlayAreaCamera,
+    ));
+}
+
+fn setup(
+    mut commands: Commands,
+    fonts: Res<UiFonts>,
+    models: Res<GameModels>,
+    locale: Res<Locale>,
+    theme: Res<Theme>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut rng: ResMut<GameRng>,
+    mut camera_query: Query<(&mut Camera, &mut Transform)>,
+    tuning: Res<FroggerTuning>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut timer: ResMut<GameTimer>,
+) {
+    // Camera, back from wherever the last run left it
+    let (mut camera, mut camera_transform) = camera_query.single_mut();
+    camera.looking_at = PLAYER_INITIAL_POSITION;
+    *camera_transform = initial_camera_transform();
+    scoreboard.score = 0;
+    timer
+        .0
+        .set_duration(Duration::from_secs_f32(tuning.game_time_limit));
+    timer.0.reset();
+    // Light
+    commands.spawn(PointLightBundle {
+        transform: Transform::from_xyz(4.0, 10.0, 4.0),
+        point_light: PointLight {
+            intensity: 2_000_000.0,
+            shadows_enabled: true,
+            range: 30.0,
+            ..default()
+        },
+        ..default()
+    });
+    // Board
+    for i in 0..BOARD_SIZE_I {
+        for j in 0..BOARD_SIZE_J {
+            commands.spawn(SceneBundle {
+                transform: Transform::from_xyz(i as f32, -0.2, j as f32),
+                scene: models.get(TILE_MODEL),
+                ..default()
+            });
+        }
+    }
+    // Player
+    commands.spawn((
+        SceneBundle {
+            transform: Transform {
+                translation: PLAYER_INITIAL_POSITION,
+                rotation: Quat::from_rotation_y(PI / 2.0),
+                ..default()
+            },
+            scene: models.get(PLAYER_MODEL),
+            ..default()
+        },
+        Player {
+            i: PLAYER_INITIAL_POSITION.x,
+            j: PLAYER_INITIAL_POSITION.z,
+            move_cooldown: Timer::from_seconds(0.3, TimerMode::Once),
+        },
+        Collider::aabb(PLAYER_COLLIDER_SIZE),
+    ));
+    // Obstacles
+    for i in 1..BOARD_SIZE_I - 1 {
+        if i % 2 == 0 {
+            continue;
+        }
+
+        let transform_z = rng.gen_range(0..BOARD_SIZE_J) as f32;
+        commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(Cuboid::new(OBSTACLE_SIZE, OBSTACLE_SIZE, OBSTACLE_SIZE)),
+                material: materials.add(theme.obstacle),
+                transform: Transform::from_xyz(i as f32, OBSTACLE_SIZE / 2.0, transform_z),
+                ..default()
+            },
+            Themed(ThemeColor::Obstacle),
+            Obstacle,
+            Collider::aabb(Vec2::splat(OBSTACLE_SIZE)),
+            Velocity(Vec3::new(0.0, 0.0, i as f32)),
+            Interpolated::default(),
+        ));
+    }
+    // Scoreboard
+    commands.spawn((
+        TextBundle::from_sections([
+            TextSection::new(
+                locale.get("hud_score"),
+                TextStyle {
+                    font: locale.font(),
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: theme.light_text,
+                },
+            ),
+            TextSection::from_style(TextStyle {
+                font: fonts.mono.clone(),
+                font_size: SCOREBOARD_FONT_SIZE,
+                color: theme.light_score,
+            }),
+            TextSection::new(
+                locale.get("hud_then_time"),
+                TextStyle {
+                    font: locale.font(),
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: theme.light_text,
+                },
+            ),
+            TextSection::from_style(TextStyle {
+                font: fonts.mono.clone(),
+                font_size: SCOREBOARD_FONT_SIZE,
+                color: theme.light_score,
+            }),
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: SCOREBOARD_TEXT_PADDING,
+            left: SCOREBOARD_TEXT_PADDING,
+            ..default()
+        }),
+        LocalizedText::new("hud_score").with_section(2, "hud_then_time"),
+        Scoreboard {
+            time: tuning.game_time_limit,
+            score: 0,
+        },
+    ));
+}
+
+fn check_for_collision(
+    mut collision_events: EventReader<CollisionStarted>,
+    mut player_query: Query<(Entity, &mut Player, &mut Transform), With<Player>>,
+    obstacle_query: Query<(), With<Obstacle>>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    let (player_entity, mut player, mut player_transform) = player_query.single_mut();
+
+    for event in collision_events.read() {
+        if event.entity != player_entity || !obstacle_query.contains(event.other) {
+            continue;
+        }
+
+        scoreboard.score -= 1;
+        sfx_events.send(SfxEvent::Hit);
+        player.i = PLAYER_INITIAL_POSITION.x;
+        player.j = PLAYER_INITIAL_POSITION.z;
+        player_transform.translation = PLAYER_INITIAL_POSITION;
+        break;
+    }
+}
+
+fn update_scoreboard(
+    scoreboard: Res<Scoreboard>,
+    mut scoreboard_query: Query<&mut Text, With<Scoreboard>>,
+) {
+    let mut text = scoreboard_query.single_mut();
+    text.sections[1].value = scoreboard.score.to_string();
+    text.sections[3].value = scoreboard.time.to_string();
+}
+
+fn update_game_timer(
+    mut scoreboard: ResMut<Scoreboard>,
+    time: Res<Time>,
+    mut timer: ResMut<GameTimer>,
+    mut finished_events: EventWriter<GameFinished>,
+) {
+    scoreboard.time = timer.0.remaining_secs().round();
+
+    if timer.0.tick(time.delta()).just_finished() {
+        timer.0.reset();
+        finished_events
+            .send(GameFinished::new(Outcome::GameOver).with_score(scoreboard.score as f32));
+    }
+}
+
+fn move_player(
+    action_input: Res<ButtonInput<Action>>,
+    mut player_query: Query<(&mut Player, &mut Transform), With<Player>>,
+    time: Res<Time>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    let (mut player, mut player_transform) = player_query.single_mut();
+
+    if player.move_cooldown.tick(time.delta()).finished() {
+        let mut moved = false;
+        let mut rotation = 0.0;
+
+        if action_input.pressed(Action::MoveUp) {
+            if player.i < BOARD_SIZE_I as f32 - 1.0 {
+                player.i += 1.0;
+            }
+            rotation = PI / 2.0;
+            moved = true;
+        }
+        if action_input.pressed(Action::MoveDown) {
+            if player.i > 0.0 {
+                player.i -= 1.0;
+            }
+            rotation = -PI / 2.0;
+            moved = true;
+        }
+        if action_input.pressed(Action::MoveRight) {
+            if player.j < BOARD_SIZE_J as f32 - 1.0 {
+                player.j += 1.0;
+            }
+            rotation = 0.0;
+            moved = true;
+        }
+        if action_input.pressed(Action::MoveLeft) {
+            if player.j > 0.0 {
+                player.j -= 1.0;
+            }
+            rotation = PI;
+            moved = true;
+        }
+
+        if moved {
+            player.move_cooldown.reset();
+            sfx_events.send(SfxEvent::Jump);
+            player_transform.translation = Vec3::new(player.i, 0.0, player.j);
+            player_transform.rotation = Quat::from_rotation_y(rotation);
+        }
+    }
+}
+
+fn move_obstacle(mut obstacle_query: Query<(&Transform, &mut Velocity), With<Obstacle>>) {
+    for (obstacle_transform, mut obstacle_velocity) in &mut obstacle_query {
+        let left_board_collision = 0.0 > obstacle_transform.translation.z;
+        let right_board_collision =
+            (BOARD_SIZE_J as f32) < obstacle_transform.translation.z + OBSTACLE_SIZE;
+
+        if left_board_collision || right_board_collision {
+            obstacle_velocity.z = -obstacle_velocity.z;
+        }
+    }
+}
+
+fn focus_camera(
+    time: Res<Time>,
+    tuning: Res<FroggerTuning>,
+    mut camera_query: Query<(&mut Camera, &mut Transform), (With<Camera3d>, Without<Player>)>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    let (mut camera, mut camera_transform) = camera_query.single_mut();
+    let player_transform = player_query.single();
+    let motion_time = tuning.camera_speed * time.delta_seconds();
+
+    // move camera position
+    let mut camera_motion =
+        player_transform.translation + CAMERA_DISTANCE - camera_transform.translation;
+    if camera_motion.length() > 0.2 {
+        camera_motion *= motion_time;
+        camera_transform.translation += camera_motion;
+    }
+
+    // move camera looking position
+    let mut camera_motion = player_transform.translation - camera.looking_at;
+    if camera_motion.length() > 0.2 {
+        camera_motion *= motion_time;
+        camera.looking_at += camera_motion;
+    }
+    *camera_transform = camera_transform.looking_at(camera.looking_at, Vec3::Y);
+}
+
+fn goal_player(
+    mut player_query: Query<(&mut Player, &mut Transform), With<Player>>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    let (mut player, mut player_transform) = player_query.single_mut();
+
+    if player.i >= BOARD_SIZE_I as f32 - 1.0 {
+        scoreboard.score += 1;
+        sfx_events.send(SfxEvent::Pickup);
+        player.i = PLAYER_INITIAL_POSITION.x;
+        player.j = PLAYER_INITIAL_POSITION.z;
+        player_transform.translation = PLAYER_INITIAL_POSITION;
+    }
+}
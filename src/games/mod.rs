@@ -0,0 +1,8 @@
+pub mod catch_game;
+pub mod click_game;
+pub mod doodle_jump;
+pub mod flappy_bird;
+pub mod frogger;
+pub mod run_and_jump;
+pub mod shooting_2d;
+pub mod timing_game;
@@ -0,0 +1,447 @@
+use crate::{
+    collision::{Collider, Collision, CollisionStarted, Contacts},
+    debug_overlay::DebugOverlayAppExt,
+    high_scores::{ScoreOrder, Stat},
+    input::{Action, InputBinding, InputMap},
+    interpolation::{Interpolated, InterpolationSet},
+    loading::AssetManifest,
+    menu::MenuChosen,
+    mini_game::{GameSystems, HostedGamePlugin, Localized, MiniGame, MiniGameInfo, MiniGameSet},
+    music::GameMusic,
+    play_area::{PlayArea, PlayAreaCamera},
+    result_screen::{GameFinished, Outcome, ResultButton, ResultScreenSet},
+    resuming,
+    sfx::SfxEvent,
+    theme::{Theme, ThemeColor, Themed},
+    tuning::{Tuning, TuningCheck, TuningPlugin},
+    AppState, GameplaySet, Velocity,
+};
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle, time::Stopwatch, transform::TransformSystem};
+use serde::{Deserialize, Serialize};
+
+pub const WINDOW_SIZE: Vec2 = Vec2::new(800.0, 600.0);
+const GAME_ID: &str = "run_and_jump";
+
+const TILE_SIZE: f32 = 40.0;
+
+const CAMERA_FOCUS_OFFSET: f32 = -200.0;
+
+const MAX_STAGE_COUNT: u32 = 5;
+
+const PLAYER_SIZE: Vec3 = Vec3::new(25.0, 25.0, 0.0);
+
+/// From `assets/tuning/run_and_jump.ron`. The speeds are per second, tuned to match the original
+/// per-frame values at 60 fps.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, PartialEq, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct RunAndJumpTuning {
+    pub player_speed: f32,
+    pub player_jump: f32,
+    pub player_jump_deceleration: f32,
+    pub player_fall_speed: f32,
+    pub player_jump_count: u32,
+}
+
+impl Tuning for RunAndJumpTuning {
+    const PATH: &'static str = "tuning/run_and_jump.ron";
+    const BUILT_IN: &'static str = include_str!("../../../assets/tuning/run_and_jump.ron");
+
+    fn check(&self, check: &mut TuningCheck) {
+        check
+            .range("player_speed", self.player_speed, 1.0..=1000.0)
+            .range("player_jump", self.player_jump, 1.0..=10000.0)
+            .range(
+                "player_jump_deceleration",
+                self.player_jump_deceleration,
+                1.0..=100000.0,
+            )
+            // Landing on a tile takes some falling into it
+            .range("player_fall_speed", self.player_fall_speed, 1.0..=2000.0)
+            .range("player_jump_count", self.player_jump_count, 1..=10);
+    }
+}
+
+#[derive(Resource)]
+struct StageCount(u32);
+
+#[derive(Resource, Default)]
+struct StageTime(Stopwatch);
+
+pub struct RunAndJumpPlugin;
+
+impl MiniGame for RunAndJumpPlugin {
+    const INFO: MiniGameInfo = MiniGameInfo {
+        id: GAME_ID,
+        name: Localized {
+            en: "Run and Jump",
+            ja: "ランアンドジャンプ",
+        },
+        description: Localized {
+            en: "Jump over the obstacles and reach the goal.",
+            ja: "プレイヤーを操作して障害物を回避してゴールを目指すゲーム。",
+        },
+        controls: Localized {
+            en: "Space, click or tap to jump, again mid-air",
+            ja: "スペースキーかクリック、タップでジャンプ、空中でもう一度",
+        },
+        window_size: WINDOW_SIZE,
+        colors: &[],
+        score_order: ScoreOrder::HigherIsBetter,
+        assets: AssetManifest::EMPTY,
+        music: GameMusic::DEFAULT,
+    };
+}
+
+impl Plugin for RunAndJumpPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(HostedGamePlugin::<Self>::default())
+            .add_plugins(TuningPlugin::<RunAndJumpTuning>::default())
+            .count_in_debug_overlay::<TileGround>("TileGround")
+            .add_systems(
+                OnEnter(Self::active()),
+                (insert_game_resources, setup_camera).in_set(MiniGameSet::Setup),
+            )
+            .add_systems(
+                OnExit(Self::active()),
+                remove_game_resources.in_set(MiniGameSet::Teardown),
+            )
+            // Every run from the title starts at the first stage
+            .add_systems(
+                OnEnter(AppState::MainMenu),
+                reset_stage_count.in_set(GameSystems(GAME_ID)),
+            )
+            .add_systems(
+                OnEnter(AppState::InGame),
+                (setup_tilemap, setup_player, reset_stage_time, reset_camera)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(not(resuming)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    tick_stage_time,
+                    player_gravity,
+                    jump_player,
+                    ground_collision,
+                    goal_collision,
+                )
+                    .chain()
+                    .in_set(GameplaySet)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            )
+            // Follows the drawn player, not the simulated one, so it doesn't judder
+            .add_systems(
+                PostUpdate,
+                focus_camera_on_player
+                    .after(InterpolationSet)
+                    .before(TransformSystem::TransformPropagate)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            )
+            // Read before the next stage is set up on entering `InGame`
+            .add_systems(
+                Update,
+                advance_stage
+                    .after(ResultScreenSet)
+                    .in_set(GameSystems(GAME_ID)),
+            );
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct TileMap {
+    map: Vec<Vec<u32>>,
+}
+
+#[derive(Component)]
+struct TileGround;
+
+#[derive(Component)]
+struct TileGoal;
+
+#[derive(Component)]
+struct Player {
+    vel_y: f32,
+    jump_count: u32,
+    on_ground: bool,
+}
+
+// Kept across runs, only while this game is active
+fn insert_game_resources(mut commands: Commands, theme: Res<Theme>) {
+    commands.insert_resource(Time::<Fixed>::from_seconds(1.0 / 60.0));
+    commands.insert_resource(ClearColor(theme.background));
+    commands.insert_resource(
+        InputMap::new(GAME_ID)
+            .bind(Action::Jump, KeyCode::Space)
+            .bind(Action::Jump, GamepadButtonType::South)
+            .bind(Action::Jump, InputBinding::Pointer)
+            .load(),
+    );
+    commands.insert_resource(StageCount(1));
+    commands.init_resource::<StageTime>();
+}
+
+fn remove_game_resources(mut commands: Commands) {
+    commands.remove_resource::<StageCount>();
+    commands.remove_resource::<StageTime>();
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn((Camera2dBundle::default(), PlayAreaCamera));
+}
+
+fn load_tilemap(stage_count: u32) -> TileMap {
+    match stage_count {
+        1 => serde_json::from_slice(include_bytes!("stage_1.json")).unwrap(),
+        2 => serde_json::from_slice(include_bytes!("stage_2.json")).unwrap(),
+        3 => serde_json::from_slice(include_bytes!("stage_3.json")).unwrap(),
+        4 => serde_json::from_slice(include_bytes!("stage_4.json")).unwrap(),
+        5 => serde_json::from_slice(include_bytes!("stage_5.json")).unwrap(),
+        _ => panic!("invalid stage count"),
+    }
+}
+
+fn setup_tilemap(
+    mut commands: Commands,
+    stage_count: Res<StageCount>,
+    play_area: Res<PlayArea>,
+    theme: Res<Theme>,
+) {
+    let stage_count: u32 = stage_count.0;
+    let tile_map: TileMap = load_tilemap(stage_count);
+    let window_top_left = play_area.top_left();
+
+    for (y, row) in tile_map.map.iter().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            if cell > &0 {
+                let tile_y = window_top_left.y - TILE_SIZE * (y as f32 + 0.5);
+                let tile_x = window_top_left.x + TILE_SIZE * (x as f32 + 0.5);
+
+                let tile_ground = (
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: theme.ground,
+                            ..default()
+                        },
+                        transform: Transform {
+                            translation: Vec3::new(tile_x, tile_y, 0.0),
+                            scale: Vec3::splat(TILE_SIZE),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Themed(ThemeColor::Ground),
+                    TileGround,
+                    Collider::aabb(Vec2::splat(TILE_SIZE)),
+                );
+                let tile_goal = (
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: theme.goal,
+                            ..default()
+                        },
+                        transform: Transform {
+                            translation: Vec3::new(tile_x, tile_y, 0.0),
+                            scale: Vec3::splat(TILE_SIZE),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Themed(ThemeColor::Goal),
+                    TileGoal,
+                    Collider::aabb(Vec2::splat(TILE_SIZE)),
+                );
+
+                match cell {
+                    1 => {
+                        commands.spawn(tile_ground);
+                    }
+                    // Hollow, so it stands out from the ground by more than its colour
+                    2 => {
+                        commands.spawn(tile_goal).with_children(|parent| {
+                            parent.spawn((
+                                SpriteBundle {
+                                    sprite: Sprite {
+                                        color: theme.background,
+                                        ..default()
+                                    },
+                                    transform: Transform {
+                                        translation: Vec3::new(0.0, 0.0, 0.1),
+                                        scale: Vec3::splat(0.5),
+                                        ..default()
+                                    },
+                                    ..default()
+                                },
+                                Themed(ThemeColor::Background),
+                            ));
+                        });
+                    }
+                    _ => {
+                        commands.spawn(SpriteBundle::default());
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn setup_player(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    theme: Res<Theme>,
+    play_area: Res<PlayArea>,
+    tuning: Res<RunAndJumpTuning>,
+) {
+    let player_x = play_area.left() + PLAYER_SIZE.x;
+    let player_y = play_area.size().y / 5.0 - PLAYER_SIZE.y;
+
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes.add(RegularPolygon::new(1.0, 4)).into(),
+            material: materials.add(ColorMaterial::from(theme.player)),
+            transform: Transform {
+                translation: Vec3::new(player_x, player_y, 1.0),
+                scale: PLAYER_SIZE,
+                ..default()
+            },
+            ..default()
+        },
+        Themed(ThemeColor::Player),
+        Player {
+            vel_y: 0.0,
+            jump_count: tuning.player_jump_count,
+            on_ground: false,
+        },
+        Velocity(Vec3::new(tuning.player_speed, 0.0, 0.0)),
+        Collider::aabb(PLAYER_SIZE.truncate()),
+        Interpolated::default(),
+    ));
+}
+
+fn focus_camera_on_player(
+    player_query: Query<&Transform, With<Player>>,
+    mut camera_query: Query<&mut Transform, (With<Camera2d>, Without<Player>)>,
+) {
+    let player_transform = player_query.single();
+    let mut camera_transform = camera_query.single_mut();
+
+    if player_transform.translation.x > CAMERA_FOCUS_OFFSET {
+        camera_transform.translation.x = player_transform.translation.x - CAMERA_FOCUS_OFFSET;
+    }
+}
+
+fn player_gravity(
+    mut player_query: Query<(&mut Transform, &mut Player), With<Player>>,
+    mut finished_events: EventWriter<GameFinished>,
+    play_area: Res<PlayArea>,
+    tuning: Res<RunAndJumpTuning>,
+    time: Res<Time>,
+) {
+    if let Ok((mut player_transform, mut player)) = player_query.get_single_mut() {
+        if !player.on_ground {
+            player_transform.translation.y -= tuning.player_fall_speed * time.delta_seconds();
+            if player.jump_count >= tuning.player_jump_count {
+                player.jump_count = 1;
+            }
+        }
+
+        if player_transform.translation.y < play_area.bottom() - player_transform.scale.y {
+            finished_events.send(GameFinished::new(Outcome::GameOver));
+        }
+    }
+}
+
+fn jump_player(
+    action_input: Res<ButtonInput<Action>>,
+    mut player_query: Query<(&mut Player, &mut Transform), With<Player>>,
+    tuning: Res<RunAndJumpTuning>,
+    time: Res<Time>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    if let Ok((mut player, mut player_transform)) = player_query.get_single_mut() {
+        if action_input.just_pressed(Action::Jump) && player.jump_count > 0 {
+            player.vel_y += tuning.player_jump;
+            player.jump_count -= 1;
+            sfx_events.send(SfxEvent::Jump);
+        }
+
+        if player.vel_y > 0.0 {
+            player.vel_y -= tuning.player_jump_deceleration * time.delta_seconds();
+            player_transform.translation.y += player.vel_y * time.delta_seconds();
+        }
+    }
+}
+
+fn ground_collision(
+    mut player_query: Query<(Entity, &mut Player, &mut Velocity)>,
+    ground_query: Query<(), With<TileGround>>,
+    contacts: Res<Contacts>,
+    tuning: Res<RunAndJumpTuning>,
+) {
+    let (player_entity, mut player, mut player_velocity) = player_query.single_mut();
+    player.on_ground = false;
+    player_velocity.x = tuning.player_speed;
+
+    for (other, side) in contacts.of(player_entity) {
+        if side == Collision::Top && ground_query.contains(other) {
+            player.on_ground = true;
+            player.jump_count = tuning.player_jump_count;
+        }
+    }
+}
+
+fn goal_collision(
+    mut collision_events: EventReader<CollisionStarted>,
+    player_query: Query<(), With<Player>>,
+    goal_query: Query<(), With<TileGoal>>,
+    stage_count: Res<StageCount>,
+    stage_time: Res<StageTime>,
+    mut finished_events: EventWriter<GameFinished>,
+) {
+    let reached_goal = collision_events
+        .read()
+        .filter(|event| player_query.contains(event.entity) && goal_query.contains(event.other))
+        .count()
+        > 0;
+
+    if reached_goal {
+        // Ranked by the furthest stage cleared, the clear time is kept alongside
+        let mut finished = GameFinished::new(Outcome::GameClear)
+            .with_score(stage_count.0 as f32)
+            .with_stat(Stat::Time, format!("{:.1}s", stage_time.0.elapsed_secs()));
+        if stage_count.0 < MAX_STAGE_COUNT {
+            finished = finished.with_next_stage();
+        }
+        finished_events.send(finished);
+    }
+}
+
+fn reset_stage_count(mut stage_count: ResMut<StageCount>) {
+    stage_count.0 = 1;
+}
+
+fn reset_stage_time(mut stage_time: ResMut<StageTime>) {
+    stage_time.0.reset();
+}
+
+fn tick_stage_time(mut stage_time: ResMut<StageTime>, time: Res<Time>) {
+    stage_time.0.tick(time.delta());
+}
+
+fn advance_stage(
+    mut chosen_events: EventReader<MenuChosen<ResultButton>>,
+    mut stage_count: ResMut<StageCount>,
+) {
+    for MenuChosen(button) in chosen_events.read() {
+        if *button == ResultButton::NextStage && stage_count.0 < MAX_STAGE_COUNT {
+            stage_count.0 += 1;
+        }
+    }
+}
+
+fn reset_camera(mut camera_query: Query<&mut Transform, With<Camera2d>>) {
+    let mut camera_transform = camera_query.single_mut();
+    camera_transform.translation.x = 0.0;
+}
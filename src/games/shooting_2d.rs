@@ -0,0 +1,568 @@
+use crate::{
+    collision::{Collider, CollisionStarted},
+    debug_overlay::DebugOverlayAppExt,
+    high_scores::{ScoreOrder, Stat},
+    input::{action_axis, Action, AxisDirection, InputBinding, InputMap},
+    interpolation::Interpolated,
+    loading::{AssetManifest, UiFonts},
+    locale::{Locale, LocalizedText},
+    mini_game::{GameSystems, HostedGamePlugin, Localized, MiniGame, MiniGameInfo, MiniGameSet},
+    music::GameMusic,
+    play_area::{PlayArea, PlayAreaCamera},
+    pointer::Pointers,
+    result_screen::{GameFinished, Outcome},
+    resuming,
+    sfx::SfxEvent,
+    theme::{Theme, ThemeColor, Themed},
+    tuning::{Tuning, TuningCheck, TuningPlugin},
+    AppState, GameplaySet, Velocity,
+};
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle, time::Stopwatch};
+use serde::Deserialize;
+use std::time::Duration;
+
+pub const WINDOW_SIZE: Vec2 = Vec2::new(700.0, 700.0);
+const GAME_ID: &str = "2d_shooting";
+
+const PLAYER_SIZE: f32 = 15.0;
+const GAP_BETWEEN_PLAYER_AND_FLOOR: f32 = 40.0;
+const PLAYER_PADDING: f32 = 20.0;
+
+const ENEMY_SIZE: f32 = 15.0;
+const GAP_BETWEEN_ENEMY_AND_TOP: f32 = 40.0;
+const INITIAL_ENEMY_DIRECTION: Vec2 = Vec2::new(-0.5, 0.0);
+
+const SCOREBOARD_FONT_SIZE: f32 = 20.0;
+const SCOREBOARD_TEXT_PADDING: f32 = 5.0;
+const SCOREBOARD_SIZE: Vec2 = Vec2::new(
+    WINDOW_SIZE.x,
+    SCOREBOARD_FONT_SIZE + SCOREBOARD_TEXT_PADDING,
+);
+
+const BULLET_SIZE: f32 = 5.0;
+
+const PLAYER_LAYER: u32 = 1 << 1;
+const ENEMY_LAYER: u32 = 1 << 2;
+const PLAYER_BULLET_LAYER: u32 = 1 << 3;
+const ENEMY_BULLET_LAYER: u32 = 1 << 4;
+
+/// From `assets/tuning/2d_shooting.ron`.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, PartialEq, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Shooting2dTuning {
+    pub player_speed: f32,
+    pub player_hp: f32,
+    pub enemy_speed: f32,
+    pub enemy_hp: f32,
+    pub enemy_attack_interval: f32,
+    pub bullet_speed: f32,
+}
+
+impl Tuning for Shooting2dTuning {
+    const PATH: &'static str = "tuning/2d_shooting.ron";
+    const BUILT_IN: &'static str = include_str!("../../assets/tuning/2d_shooting.ron");
+
+    fn check(&self, check: &mut TuningCheck) {
+        check
+            .range("player_speed", self.player_speed, 1.0..=2000.0)
+            .range("player_hp", self.player_hp, 1.0..=99.0)
+            .range("enemy_speed", self.enemy_speed, 0.0..=2000.0)
+            .range("enemy_hp", self.enemy_hp, 1.0..=99.0)
+            .range(
+                "enemy_attack_interval",
+                self.enemy_attack_interval,
+                0.05..=10.0,
+            )
+            .range("bullet_speed", self.bullet_speed, 1.0..=5000.0);
+    }
+}
+
+pub struct Shooting2dPlugin;
+
+impl MiniGame for Shooting2dPlugin {
+    const INFO: MiniGameInfo = MiniGameInfo {
+        id: GAME_ID,
+        name: Localized {
+            en: "2D Shooting",
+            ja: "2Dシューティングゲーム",
+        },
+        description: Localized {
+            en: "Shoot down the enemy at the top of the screen.",
+            ja: "画面上部にいる敵を弾を打って倒すゲーム。",
+        },
+        controls: Localized {
+            en: "Arrows or WASD to move, Space to shoot",
+            ja: "矢印キーかWASDで移動、スペースキーで弾を打つ",
+        },
+        window_size: WINDOW_SIZE,
+        colors: &[
+            (ThemeColor::DarkBackground, Color::BLACK),
+            (ThemeColor::Player, Color::srgb(0.3, 0.9, 0.3)),
+        ],
+        score_order: ScoreOrder::LowerIsBetter,
+        assets: AssetManifest::EMPTY,
+        music: GameMusic::DEFAULT,
+    };
+}
+
+impl Plugin for Shooting2dPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(HostedGamePlugin::<Self>::default())
+            .add_plugins(TuningPlugin::<Shooting2dTuning>::default())
+            .count_in_debug_overlay::<Bullet>("Bullet")
+            .add_systems(
+                OnEnter(Self::active()),
+                (insert_game_resources, setup_camera).in_set(MiniGameSet::Setup),
+            )
+            .add_systems(
+                OnExit(Self::active()),
+                remove_game_resources.in_set(MiniGameSet::Teardown),
+            )
+            .add_systems(
+                OnEnter(AppState::InGame),
+                setup.run_if(not(resuming)).in_set(GameSystems(GAME_ID)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    move_player,
+                    player_shoot,
+                    move_enemy,
+                    enemy_shoot,
+                    bullet_collision,
+                    remove_bullet,
+                    tick_battle_time,
+                )
+                    .chain()
+                    .in_set(GameplaySet)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(
+                Update,
+                (drag_player, update_scoreboard)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            );
+    }
+}
+
+// Kept across runs, only while this game is active
+fn insert_game_resources(mut commands: Commands, theme: Res<Theme>, tuning: Res<Shooting2dTuning>) {
+    commands.insert_resource(Time::<Fixed>::from_seconds(1.0 / 60.0));
+    commands.insert_resource(ClearColor(theme.dark_background));
+    commands.insert_resource(
+        InputMap::new(GAME_ID)
+            .bind(Action::MoveLeft, KeyCode::ArrowLeft)
+            .bind(Action::MoveLeft, KeyCode::KeyA)
+            .bind(Action::MoveRight, KeyCode::ArrowRight)
+            .bind(Action::MoveRight, KeyCode::KeyD)
+            .bind(Action::MoveUp, KeyCode::ArrowUp)
+            .bind(Action::MoveUp, KeyCode::KeyW)
+            .bind(Action::MoveDown, KeyCode::ArrowDown)
+            .bind(Action::MoveDown, KeyCode::KeyS)
+            .bind(
+                Action::MoveLeft,
+                (GamepadAxisType::LeftStickX, AxisDirection::Negative),
+            )
+            .bind(
+                Action::MoveRight,
+                (GamepadAxisType::LeftStickX, AxisDirection::Positive),
+            )
+            .bind(
+                Action::MoveUp,
+                (GamepadAxisType::LeftStickY, AxisDirection::Positive),
+            )
+            .bind(
+                Action::MoveDown,
+                (GamepadAxisType::LeftStickY, AxisDirection::Negative),
+            )
+            .bind(Action::MoveLeft, GamepadButtonType::DPadLeft)
+            .bind(Action::MoveRight, GamepadButtonType::DPadRight)
+            .bind(Action::MoveUp, GamepadButtonType::DPadUp)
+            .bind(Action::MoveDown, GamepadButtonType::DPadDown)
+            .bind(Action::Fire, KeyCode::Space)
+            .bind(Action::Fire, GamepadButtonType::South)
+            .bind(Action::Fire, InputBinding::Pointer)
+            .load(),
+    );
+    commands.init_resource::<BattleTime>();
+    commands.insert_resource(Scoreboard {
+        player_hp: tuning.player_hp,
+        enemy_hp: tuning.enemy_hp,
+    });
+    commands.insert_resource(EnemyAttackTimer(Timer::from_seconds(
+        tuning.enemy_attack_interval,
+        TimerMode::Repeating,
+    )));
+}
+
+fn remove_game_resources(mut commands: Commands) {
+    commands.remove_resource::<BattleTime>();
+    commands.remove_resource::<Scoreboard>();
+    commands.remove_resource::<EnemyAttackTimer>();
+}
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Enemy;
+
+#[derive(Component)]
+struct Bullet;
+
+#[derive(Component)]
+struct Health {
+    hp: f32,
+}
+
+#[derive(Resource, Component)]
+struct Scoreboard {
+    player_hp: f32,
+    enemy_hp: f32,
+}
+
+#[derive(Resource)]
+struct EnemyAttackTimer(Timer);
+
+#[derive(Resource, Default)]
+struct BattleTime(Stopwatch);
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn((Camera2dBundle::default(), PlayAreaCamera));
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    fonts: Res<UiFonts>,
+    locale: Res<Locale>,
+    theme: Res<Theme>,
+    play_area: Res<PlayArea>,
+    tuning: Res<Shooting2dTuning>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut battle_time: ResMut<BattleTime>,
+    mut timer: ResMut<EnemyAttackTimer>,
+) {
+    scoreboard.player_hp = tuning.player_hp;
+    scoreboard.enemy_hp = tuning.enemy_hp;
+    battle_time.0.reset();
+    timer.0.reset();
+    // Player
+    let player_y = play_area.bottom() + GAP_BETWEEN_PLAYER_AND_FLOOR;
+
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes.add(RegularPolygon::new(PLAYER_SIZE, 3)).into(),
+            material: materials.add(ColorMaterial::from(theme.player)),
+            transform: Transform::from_translation(Vec3::new(0., player_y, 0.)),
+            ..default()
+        },
+        Themed(ThemeColor::Player),
+        Player,
+        Health {
+            hp: tuning.player_hp,
+        },
+        Collider::aabb(Vec2::splat(PLAYER_SIZE)).with_layers(PLAYER_LAYER, ENEMY_BULLET_LAYER),
+        Interpolated::default(),
+    ));
+    // Enemy
+    let enemy_y = play_area.top() - SCOREBOARD_SIZE.y - GAP_BETWEEN_ENEMY_AND_TOP;
+
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes.add(RegularPolygon::new(ENEMY_SIZE, 4)).into(),
+            material: materials.add(ColorMaterial::from(theme.enemy)),
+            transform: Transform::from_translation(Vec3::new(0., enemy_y, 0.)),
+            ..default()
+        },
+        Themed(ThemeColor::Enemy),
+        Enemy,
+        Velocity(INITIAL_ENEMY_DIRECTION.normalize().extend(0.0) * tuning.enemy_speed),
+        Health {
+            hp: tuning.enemy_hp,
+        },
+        Collider::aabb(Vec2::splat(ENEMY_SIZE)).with_layers(ENEMY_LAYER, PLAYER_BULLET_LAYER),
+        Interpolated::default(),
+    ));
+    // Scoreboard
+    let font_bold = locale.font();
+    let font_medium = fonts.mono.clone();
+    let text_closure = |font: Handle<Font>, text: String| -> TextSection {
+        let style = TextStyle {
+            font,
+            font_size: SCOREBOARD_FONT_SIZE,
+            color: theme.text,
+        };
+        TextSection::new(text, style)
+    };
+
+    commands.spawn((
+        TextBundle::from_sections([
+            text_closure(font_bold.clone(), locale.get("hud_player").to_string()),
+            text_closure(font_medium.clone(), tuning.player_hp.to_string()),
+            text_closure(font_bold.clone(), locale.get("hud_enemy").to_string()),
+            text_closure(font_medium.clone(), tuning.enemy_hp.to_string()),
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(SCOREBOARD_TEXT_PADDING),
+            left: Val::Px(SCOREBOARD_TEXT_PADDING),
+            ..default()
+        }),
+        LocalizedText::new("hud_player").with_section(2, "hud_enemy"),
+        Scoreboard {
+            player_hp: tuning.player_hp,
+            enemy_hp: tuning.enemy_hp,
+        },
+    ));
+    // Scoreboard background
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: theme.background,
+                custom_size: Some(SCOREBOARD_SIZE),
+                ..default()
+            },
+            transform: Transform::from_translation(
+                Vec2::new(0.0, play_area.top() - SCOREBOARD_SIZE.y / 2.).extend(0.0),
+            ),
+            ..default()
+        },
+        Themed(ThemeColor::Background),
+    ));
+}
+
+fn update_scoreboard(
+    scoreboard: Res<Scoreboard>,
+    mut scoreboard_query: Query<&mut Text, With<Scoreboard>>,
+) {
+    let mut text = scoreboard_query.single_mut();
+    text.sections[1].value = scoreboard.player_hp.to_string();
+    text.sections[3].value = scoreboard.enemy_hp.to_string();
+}
+
+fn move_player(
+    action_axes: Res<Axis<Action>>,
+    mut player_query: Query<&mut Transform, With<Player>>,
+    play_area: Res<PlayArea>,
+    tuning: Res<Shooting2dTuning>,
+    time_step: Res<Time<Fixed>>,
+) {
+    if player_query.is_empty() {
+        return;
+    }
+
+    let mut player_transform = player_query.single_mut();
+    let direction = Vec2::new(
+        action_axis(&action_axes, Action::MoveLeft, Action::MoveRight),
+        action_axis(&action_axes, Action::MoveDown, Action::MoveUp),
+    );
+
+    let new_player_position = player_transform.translation.truncate()
+        + direction * tuning.player_speed * time_step.delta().as_secs_f32();
+    set_player_position(&play_area, &mut player_transform, new_player_position);
+}
+
+// Dragging moves the player as far as the finger went, every frame rather than every tick
+fn drag_player(
+    pointers: Res<Pointers>,
+    play_area: Res<PlayArea>,
+    mut player_query: Query<&mut Transform, With<Player>>,
+) {
+    let drag = pointers.world_drag(&play_area);
+    if drag == Vec2::ZERO || player_query.is_empty() {
+        return;
+    }
+
+    let mut player_transform = player_query.single_mut();
+    let new_player_position = player_transform.translation.truncate() + drag;
+    set_player_position(&play_area, &mut player_transform, new_player_position);
+}
+
+fn set_player_position(play_area: &PlayArea, player_transform: &mut Transform, position: Vec2) {
+    let left_bound = play_area.left() + PLAYER_SIZE / 2.0 + PLAYER_PADDING;
+    let right_bound = play_area.right() - PLAYER_SIZE / 2.0 - PLAYER_PADDING;
+    let up_bound = play_area.bottom() + PLAYER_SIZE / 2.0 + PLAYER_PADDING;
+    let down_bound = play_area.top() - PLAYER_SIZE / 2.0 - PLAYER_PADDING - SCOREBOARD_SIZE.y;
+
+    player_transform.translation.x = position.x.clamp(left_bound, right_bound);
+    player_transform.translation.y = position.y.clamp(up_bound, down_bound);
+}
+
+fn player_shoot(
+    action_input: Res<ButtonInput<Action>>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    theme: Res<Theme>,
+    tuning: Res<Shooting2dTuning>,
+    player_query: Query<&Transform, With<Player>>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    if player_query.is_empty() {
+        return;
+    }
+
+    let player_transform = player_query.single();
+
+    if action_input.just_pressed(Action::Fire) {
+        sfx_events.send(SfxEvent::Shoot);
+
+        // Bullet
+        let bullet_y = player_transform.translation.y + PLAYER_SIZE / 2.0 + BULLET_SIZE;
+
+        // Long bolts, unlike the enemy's round bullets, so they differ by more than colour
+        commands.spawn((
+            MaterialMesh2dBundle {
+                mesh: meshes
+                    .add(Rectangle::new(BULLET_SIZE, BULLET_SIZE * 3.0))
+                    .into(),
+                material: materials.add(ColorMaterial::from(theme.player)),
+                transform: Transform::from_translation(
+                    Vec2::new(player_transform.translation.x, bullet_y).extend(0.),
+                ),
+                ..default()
+            },
+            Themed(ThemeColor::Player),
+            Bullet,
+            Collider::circle(BULLET_SIZE / 2.0).with_layers(PLAYER_BULLET_LAYER, ENEMY_LAYER),
+            Velocity(Vec3::new(0., 0.5, 0.) * tuning.bullet_speed),
+            Interpolated::default(),
+        ));
+    }
+}
+
+fn move_enemy(
+    play_area: Res<PlayArea>,
+    mut enemy_query: Query<(&Transform, &mut Velocity), With<Enemy>>,
+) {
+    if enemy_query.is_empty() {
+        return;
+    }
+
+    let (enemy_transform, mut enemy_velocity) = enemy_query.single_mut();
+    let left_window_collision =
+        play_area.right() < enemy_transform.translation.x + ENEMY_SIZE / 2.0 + 10.0;
+    let right_window_collision =
+        play_area.left() > enemy_transform.translation.x - ENEMY_SIZE / 2.0 - 10.0;
+
+    if left_window_collision || right_window_collision {
+        enemy_velocity.x = -enemy_velocity.x;
+    }
+}
+
+fn enemy_shoot(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    theme: Res<Theme>,
+    tuning: Res<Shooting2dTuning>,
+    enemy_query: Query<&Transform, With<Enemy>>,
+    time: Res<Time>,
+    mut timer: ResMut<EnemyAttackTimer>,
+) {
+    if enemy_query.is_empty() {
+        return;
+    }
+
+    // Saved while the game runs
+    let interval = Duration::from_secs_f32(tuning.enemy_attack_interval);
+    if timer.0.duration() != interval {
+        timer.0.set_duration(interval);
+    }
+
+    let enemy_transform = enemy_query.single();
+
+    // Bullet
+    let bullet_y = enemy_transform.translation.y - ENEMY_SIZE / 2.0 - BULLET_SIZE;
+
+    if timer.0.tick(time.delta()).just_finished() {
+        commands.spawn((
+            MaterialMesh2dBundle {
+                mesh: meshes.add(Circle::new(BULLET_SIZE)).into(),
+                material: materials.add(ColorMaterial::from(theme.enemy)),
+                transform: Transform::from_translation(
+                    Vec2::new(enemy_transform.translation.x, bullet_y).extend(0.),
+                ),
+                ..default()
+            },
+            Themed(ThemeColor::Enemy),
+            Bullet,
+            Collider::circle(BULLET_SIZE / 2.0).with_layers(ENEMY_BULLET_LAYER, PLAYER_LAYER),
+            Velocity(Vec3::new(0., -0.5, 0.) * tuning.bullet_speed),
+            Interpolated::default(),
+        ));
+    }
+}
+
+fn bullet_collision(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionStarted>,
+    bullet_query: Query<(), With<Bullet>>,
+    mut health_query: Query<(&mut Health, Has<Player>)>,
+    mut scoreboard: ResMut<Scoreboard>,
+    battle_time: Res<BattleTime>,
+    mut finished_events: EventWriter<GameFinished>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    for event in collision_events.read() {
+        if !bullet_query.contains(event.entity) {
+            continue;
+        }
+        let Ok((mut health, is_player)) = health_query.get_mut(event.other) else {
+            continue;
+        };
+        // Already destroyed by another bullet this frame
+        if health.hp <= 0.0 {
+            continue;
+        }
+
+        commands.entity(event.entity).despawn();
+        health.hp -= 1.0;
+        sfx_events.send(SfxEvent::Hit);
+
+        if is_player {
+            scoreboard.player_hp -= 1.0;
+        } else {
+            scoreboard.enemy_hp -= 1.0;
+        }
+
+        if health.hp <= 0.0 {
+            commands.entity(event.other).despawn();
+            sfx_events.send(SfxEvent::Explode);
+
+            if is_player {
+                finished_events.send(GameFinished::new(Outcome::GameOver));
+            } else {
+                // Ranked by how fast the enemy went down
+                let clear_time = (battle_time.0.elapsed_secs() * 100.0).round() / 100.0;
+                finished_events.send(
+                    GameFinished::new(Outcome::GameClear)
+                        .with_score(clear_time)
+                        .with_stat(Stat::Hp, scoreboard.player_hp),
+                );
+            }
+        }
+    }
+}
+
+fn tick_battle_time(mut battle_time: ResMut<BattleTime>, time: Res<Time>) {
+    battle_time.0.tick(time.delta());
+}
+
+fn remove_bullet(
+    mut commands: Commands,
+    bullet_query: Query<(Entity, &Transform), With<Bullet>>,
+    play_area: Res<PlayArea>,
+) {
+    for (bullet_entity, bullet_transform) in bullet_query.iter() {
+        let bullet_pos = bullet_transform.translation.truncate();
+
+        if !play_area.contains(bullet_pos, Vec2::ZERO) {
+            commands.entity(bullet_entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use crate::{
+    high_scores::ScoreOrder,
+    input::{Action, InputBinding, InputMap},
+    interpolation::Interpolated,
+    loading::{AssetManifest, UiFonts},
+    locale::{Locale, LocalizedText},
+    mini_game::{GameSystems, HostedGamePlugin, Localized, MiniGame, MiniGameInfo, MiniGameSet},
+    music::GameMusic,
+    play_area::PlayAreaCamera,
+    result_screen::{GameFinished, Outcome},
+    resuming,
+    sfx::SfxEvent,
+    theme::{Theme, ThemeColor, Themed},
+    tuning::{Tuning, TuningCheck, TuningPlugin},
+    AppState, GameplaySet, Velocity,
+};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+pub const WINDOW_SIZE: Vec2 = Vec2::new(800.0, 600.0);
+const GAME_ID: &str = "timing_game";
+
+const SLIDER_SIZE: Vec2 = Vec2::new(500.0, 50.0);
+
+const CUE_SIZE: Vec2 = Vec2::new(5.0, 50.0);
+const INITIAL_CUE_DIRECTION: Vec2 = Vec2::new(0.5, -0.5);
+
+const SCOREBOARD_FONT_SIZE: f32 = 40.0;
+const SCOREBOARD_TEXT_PADDING: Val = Val::Px(5.0);
+
+/// From `assets/tuning/timing_game.ron`.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, PartialEq, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TimingGameTuning {
+    pub slider_ok_range: f32,
+    pub slider_good_range: f32,
+    pub slider_perfect_range: f32,
+    pub slider_ok_points: isize,
+    pub slider_good_points: isize,
+    pub slider_perfect_points: isize,
+    pub slider_default_points: isize,
+    pub cue_speed: f32,
+    pub attempt_count: usize,
+}
+
+impl Tuning for TimingGameTuning {
+    const PATH: &'static str = "tuning/timing_game.ron";
+    const BUILT_IN: &'static str = include_str!("../../assets/tuning/timing_game.ron");
+
+    fn check(&self, check: &mut TuningCheck) {
+        let half_slider = SLIDER_SIZE.x / 2.0;
+        check
+            .range("slider_ok_range", self.slider_ok_range, 1.0..=half_slider)
+            .range(
+                "slider_good_range",
+                self.slider_good_range,
+                1.0..=half_slider,
+            )
+            .range(
+                "slider_perfect_range",
+                self.slider_perfect_range,
+                1.0..=half_slider,
+            )
+            .rule(
+                self.slider_perfect_range < self.slider_good_range
+                    && self.slider_good_range < self.slider_ok_range,
+                "`slider_perfect_range`, `slider_good_range` and `slider_ok_range` must widen in \
+                 that order",
+            )
+            .range("slider_ok_points", self.slider_ok_points, -1000..=1000)
+            .range("slider_good_points", self.slider_good_points, -1000..=1000)
+            .range(
+                "slider_perfect_points",
+                self.slider_perfect_points,
+                -1000..=1000,
+            )
+            .range(
+                "slider_default_points",
+                self.slider_default_points,
+                -1000..=1000,
+            )
+            .range("cue_speed", self.cue_speed, 1.0..=5000.0)
+            .range("attempt_count", self.attempt_count, 1..=100);
+    }
+}
+
+impl TimingGameTuning {
+    // From the narrowest zone out, the first the cue is in decides the points
+    fn zones(&self) -> [(f32, isize); 3] {
+        [
+            (self.slider_perfect_range, self.slider_perfect_points),
+            (self.slider_good_range, self.slider_good_points),
+            (self.slider_ok_range, self.slider_ok_points),
+        ]
+    }
+}
+
+#[derive(Resource, Component)]
+struct Scoreboard {
+    score: isize,
+    attempts_left: usize,
+}
+
+pub struct TimingGamePlugin;
+
+impl MiniGame for TimingGamePlugin {
+    const INFO: MiniGameInfo = MiniGameInfo {
+        id: GAME_ID,
+        name: Localized {
+            en: "Timing Game",
+            ja: "タイミングゲーム",
+        },
+        description: Localized {
+            en: "Stop the cue right in the middle for a high score.",
+            ja: "真ん中にキューをタイミングよく合わせて高得点を狙うゲーム。",
+        },
+        controls: Localized {
+            en: "Space, click or tap to stop the cue",
+            ja: "スペースキーかクリック、タップでキューを止める",
+        },
+        window_size: WINDOW_SIZE,
+        colors: &[
+            (ThemeColor::Background, Color::WHITE),
+            (ThemeColor::Player, Color::srgb(0.4, 0.4, 0.4)),
+            (ThemeColor::Text, Color::BLACK),
+            (ThemeColor::Score, Color::BLACK),
+        ],
+        score_order: ScoreOrder::HigherIsBetter,
+        assets: AssetManifest::EMPTY,
+        music: GameMusic::DEFAULT,
+    };
+}
+
+impl Plugin for TimingGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(HostedGamePlugin::<Self>::default())
+            .add_plugins(TuningPlugin::<TimingGameTuning>::default())
+            .add_systems(
+                OnEnter(Self::active()),
+                (insert_game_resources, setup_camera).in_set(MiniGameSet::Setup),
+            )
+            .add_systems(
+                OnExit(Self::active()),
+                remove_game_resources.in_set(MiniGameSet::Teardown),
+            )
+            .add_systems(
+                OnEnter(AppState::InGame),
+                setup.run_if(not(resuming)).in_set(GameSystems(GAME_ID)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (decide_timing, bounce_cue)
+                    .chain()
+                    .in_set(GameplaySet)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_scoreboard,
+                    resize_zones.run_if(resource_changed::<TimingGameTuning>),
+                )
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            );
+    }
+}
+
+// Kept across runs, only while this game is active
+fn insert_game_resources(mut commands: Commands, theme: Res<Theme>, tuning: Res<TimingGameTuning>) {
+    commands.insert_resource(Time::<Fixed>::from_seconds(1.0 / 60.0));
+    commands.insert_resource(ClearColor(theme.background));
+    commands.insert_resource(
+        InputMap::new(GAME_ID)
+            .bind(Action::Hit, KeyCode::Space)
+            .bind(Action::Hit, GamepadButtonType::South)
+            .bind(Action::Hit, InputBinding::Pointer)
+            .load(),
+    );
+    commands.insert_resource(Scoreboard {
+        score: 0,
+        attempts_left: tuning.attempt_count,
+    });
+}
+
+fn remove_game_resources(mut commands: Commands) {
+    commands.remove_resource::<Scoreboard>();
+}
+
+#[derive(Component)]
+struct Cue;
+
+/// One of the zones drawn on the slider, by its index in `TimingGameTuning::zones`.
+#[derive(Component)]
+struct SliderZone(usize);
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn((Camera2dBundle::default(), PlayAreaCamera));
+}
+
+fn setup(
+    mut commands: Commands,
+    fonts: Res<UiFonts>,
+    locale: Res<Locale>,
+    theme: Res<Theme>,
+    tuning: Res<TimingGameTuning>,
+    mut scoreboard: ResMut<Scoreboard>,
+) {
+    scoreboard.score = 0;
+    scoreboard.attempts_left = tuning.attempt_count;
+
+    // Slider
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: theme.color(ThemeColor::Zone(0)),
+                custom_size: Some(SLIDER_SIZE),
+                ..default()
+            },
+            ..default()
+        },
+        Themed(ThemeColor::Zone(0)),
+    ));
+
+    // Widest first, so the narrower ones are drawn over it
+    tuning
+        .zones()
+        .iter()
+        .enumerate()
+        .rev()
+        .for_each(|(index, (range, _))| {
+            let color = ThemeColor::Zone(3 - index);
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: theme.color(color),
+                        custom_size: Some(Vec2::new(range * 2.0, SLIDER_SIZE.y)),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Themed(color),
+                SliderZone(index),
+            ));
+        });
+
+    // Cue
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: theme.player,
+                custom_size: Some(CUE_SIZE),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, 1.0),
+            ..default()
+        },
+        Themed(ThemeColor::Player),
+        Cue,
+        Velocity(Vec3::X * INITIAL_CUE_DIRECTION.normalize().x * tuning.cue_speed),
+        Interpolated::default(),
+    ));
+
+    // Scoreboard
+    commands.spawn((
+        TextBundle::from_sections([
+            TextSection::new(
+                locale.get("hud_score"),
+                TextStyle {
+                    font: locale.font(),
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: theme.text,
+                },
+            ),
+            TextSection::new(
+                "0",
+                TextStyle {
+                    font: fonts.mono.clone(),
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: theme.score,
+                },
+            ),
+            TextSection::new(
+                locale.get("hud_then_left"),
+                TextStyle {
+                    font: locale.font(),
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: theme.text,
+                },
+            ),
+            TextSection::new(
+                tuning.attempt_count.to_string(),
+                TextStyle {
+                    font: fonts.mono.clone(),
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: theme.score,
+                },
+            ),
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: SCOREBOARD_TEXT_PADDING,
+            left: SCOREBOARD_TEXT_PADDING,
+            ..default()
+        }),
+        LocalizedText::new("hud_score").with_section(2, "hud_then_left"),
+        Scoreboard {
+            score: 0,
+            attempts_left: tuning.attempt_count,
+        },
+    ));
+}
+
+fn decide_timing(
+    action_input: Res<ButtonInput<Action>>,
+    mut scoreboard: ResMut<Scoreboard>,
+    tuning: Res<TimingGameTuning>,
+    cue_query: Query<&Transform, With<Cue>>,
+    mut finished_events: EventWriter<GameFinished>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    let cue_transform = cue_query.single();
+
+    if action_input.just_pressed(Action::Hit) {
+        // Sends a timing event so that other systems can react to the timing
+        sfx_events.send(SfxEvent::Timing);
+
+        let cue_translation_x = cue_transform.translation.x;
+
+        scoreboard.score += tuning
+            .zones()
+            .iter()
+            .find(|(range, _)| cue_translation_x < *range && cue_translation_x > -range)
+            .map_or(tuning.slider_default_points, |(_, points)| *points);
+
+        scoreboard.attempts_left -= 1;
+        if scoreboard.attempts_left == 0 {
+            finished_events
+                .send(GameFinished::new(Outcome::GameOver).with_score(scoreboard.score as f32));
+        }
+    }
+}
+
+fn bounce_cue(mut cue_query: Query<(&Transform, &mut Velocity), With<Cue>>) {
+    for (transform, mut velocity) in &mut cue_query {
+        let transform_x = transform.translation.x;
+        if transform_x >= SLIDER_SIZE.x / 2.0 || transform_x <= -SLIDER_SIZE.x / 2.0 {
+            velocity.x = -velocity.x;
+        }
+    }
+}
+
+// Saved while the game runs, so the zones drawn stay the ones scored
+fn resize_zones(tuning: Res<TimingGameTuning>, mut zone_query: Query<(&SliderZone, &mut Sprite)>) {
+    let zones = tuning.zones();
+    for (zone, mut sprite) in &mut zone_query {
+        sprite.custom_size = Some(Vec2::new(zones[zone.0].0 * 2.0, SLIDER_SIZE.y));
+    }
+}
+
+fn update_scoreboard(
+    scoreboard: Res<Scoreboard>,
+    mut scoreboard_query: Query<&mut Text, With<Scoreboard>>,
+) {
+    let mut text = scoreboard_query.single_mut();
+    text.sections[1].value = scoreboard.score.to_string();
+    text.sections[3].value = scoreboard.attempts_left.to_string();
+}
@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    loading::UiFonts, locale::Locale, replay::ReplayRecorder, result_screen::GameFinished,
+    resuming, rng::GameRng, settings::Settings, storage, theme::Theme, AppState, CurrentGame,
+};
+
+pub const MAX_HIGH_SCORES: usize = 10;
+const HIGH_SCORES_FILE: &str = "high_scores.json";
+
+const HIGH_SCORES_FONT_SIZE: f32 = 20.0;
+const HIGH_SCORES_PADDING: Val = Val::Px(10.0);
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum ScoreOrder {
+    #[default]
+    HigherIsBetter,
+    LowerIsBetter,
+}
+
+impl ScoreOrder {
+    pub fn is_better(self, score: f32, other: f32) -> bool {
+        match self {
+            ScoreOrder::HigherIsBetter => score > other,
+            ScoreOrder::LowerIsBetter => score < other,
+        }
+    }
+}
+
+/// A game-specific detail of a run, stored by this id and shown by its name in the `Locale`.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stat {
+    Time,
+    Hp,
+}
+
+impl Stat {
+    /// The catalog key of its name.
+    pub fn label(self) -> &'static str {
+        match self {
+            Stat::Time => "time",
+            Stat::Hp => "hp",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct HighScoreEntry {
+    pub name: String,
+    pub score: f32,
+    pub date: String,
+    /// Game-specific details such as the stage reached, shown after the score.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extras: Vec<(Stat, String)>,
+}
+
+/// Top scores of every game, keyed by `CurrentGame::id`.
+#[derive(Resource, Serialize, Deserialize, Default, Debug)]
+pub struct HighScores {
+    tables: BTreeMap<String, Vec<HighScoreEntry>>,
+    #[serde(skip)]
+    latest: Option<(String, usize)>,
+}
+
+impl HighScores {
+    pub fn load() -> Self {
+        storage::load(HIGH_SCORES_FILE)
+            .and_then(|contents| {
+                serde_json::from_str(&contents)
+                    .map_err(|error| warn!("ignoring unreadable high scores: {error}"))
+                    .ok()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string_pretty(self) {
+            Ok(contents) => storage::save(HIGH_SCORES_FILE, &contents),
+            Err(error) => warn!("failed to serialize high scores: {error}"),
+        }
+    }
+
+    pub fn entries(&self, game: &str) -> &[HighScoreEntry] {
+        self.tables.get(game).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the rank the entry took, or `None` when it did not make the table.
+    pub fn insert(
+        &mut self,
+        game: &str,
+        order: ScoreOrder,
+        entry: HighScoreEntry,
+    ) -> Option<usize> {
+        let table = self.tables.entry(game.to_string()).or_default();
+        // Ties rank below the scores that got there first
+        let rank = table
+            .iter()
+            .position(|other| order.is_better(entry.score, other.score))
+            .unwrap_or(table.len());
+
+        self.latest = None;
+        if rank >= MAX_HIGH_SCORES {
+            return None;
+        }
+
+        table.insert(rank, entry);
+        table.truncate(MAX_HIGH_SCORES);
+        self.latest = Some((game.to_string(), rank));
+        Some(rank)
+    }
+
+    pub fn is_latest(&self, game: &str, rank: usize) -> bool {
+        self.latest
+            .as_ref()
+            .is_some_and(|(latest_game, latest_rank)| latest_game == game && *latest_rank == rank)
+    }
+}
+
+#[derive(Component)]
+pub struct HighScoreTable;
+
+pub struct HighScorePlugin;
+
+impl Plugin for HighScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(HighScores::load())
+            .add_systems(
+                OnEnter(AppState::InGame),
+                clear_latest_high_score.run_if(not(resuming)),
+            )
+            // Recorded before the result screen the game switches to is spawned next frame
+            .add_systems(PostUpdate, record_high_scores);
+    }
+}
+
+fn clear_latest_high_score(mut high_scores: ResMut<HighScores>) {
+    high_scores.latest = None;
+}
+
+// The score of a run's `GameFinished`, the first one like on the result screen. Only live runs count, a replayed one made the table when it was played. Its `ReplayPlayer` can
+// be gone by the time the score arrives, so the recorder tells them apart.
+fn record_high_scores(
+    mut finished_events: EventReader<GameFinished>,
+    current_game: Option<Res<CurrentGame>>,
+    recorder: Res<ReplayRecorder>,
+    settings: Res<Settings>,
+    mut high_scores: ResMut<HighScores>,
+) {
+    let finished = finished_events.read().next().cloned();
+    finished_events.clear();
+    let (Some(current_game), Some(finished)) =
+        (current_game.filter(|_| recorder.is_recording()), finished)
+    else {
+        return;
+    };
+    let Some(score) = finished.score else {
+        return;
+    };
+
+    let entry = HighScoreEntry {
+        name: settings.player_name.clone(),
+        score,
+        date: Local::now().format("%Y-%m-%d").to_string(),
+        extras: finished.stats,
+    };
+    if high_scores
+        .insert(current_game.id, current_game.score_order, entry)
+        .is_some()
+    {
+        high_scores.save();
+    }
+}
+
+pub fn spawn_high_score_table(
+    mut commands: Commands,
+    fonts: Res<UiFonts>,
+    locale: Res<Locale>,
+    theme: Res<Theme>,
+    high_scores: Res<HighScores>,
+    current_game: Option<Res<CurrentGame>>,
+    rng: Res<GameRng>,
+) {
+    let Some(current_game) = current_game else {
+        return;
+    };
+    let entries = high_scores.entries(current_game.id);
+    // Scores line up in a monospace font
+    let mono_style = |color: Color| TextStyle {
+        font: fonts.mono.clone(),
+        font_size: HIGH_SCORES_FONT_SIZE,
+        color,
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: HIGH_SCORES_PADDING,
+                    right: HIGH_SCORES_PADDING,
+                    padding: UiRect::all(HIGH_SCORES_PADDING),
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                background_color: theme.overlay.into(),
+                ..default()
+            },
+            HighScoreTable,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                locale.get("high_scores"),
+                locale.text_style(HIGH_SCORES_FONT_SIZE, theme.light_text),
+            ));
+
+            if entries.is_empty() {
+                parent.spawn(TextBundle::from_section(
+                    locale.get("no_scores_yet"),
+                    locale.text_style(HIGH_SCORES_FONT_SIZE, theme.light_text),
+                ));
+            }
+
+            for (rank, entry) in entries.iter().enumerate() {
+                let mut line = format!(
+                    "{:>2}. {:<10.10} {:>8} {}",
+                    rank + 1,
+                    entry.name,
+                    entry.score,
+                    entry.date,
+                );
+                for (stat, value) in &entry.extras {
+                    line.push_str(&format!(" {}:{value}", locale.get(stat.label())));
+                }
+
+                let color = if high_scores.is_latest(current_game.id, rank) {
+                    theme.highlight
+                } else {
+                    theme.light_text
+                };
+                parent.spawn(TextBundle::from_section(line, mono_style(color)));
+            }
+
+            // Shared to replay the same run with `--seed`
+            parent.spawn(TextBundle::from_sections([
+                TextSection::new(
+                    format!("{}: ", locale.get("seed")),
+                    locale.text_style(HIGH_SCORES_FONT_SIZE, theme.light_text),
+                ),
+                TextSection::new(rng.seed().to_string(), mono_style(theme.light_text)),
+            ]));
+        });
+}
@@ -0,0 +1,354 @@
+use std::{fmt, hash::Hash, marker::PhantomData};
+
+use bevy::{
+    app::RunFixedMainLoop,
+    ecs::system::SystemParam,
+    input::InputSystem,
+    prelude::*,
+    time::run_fixed_main_schedule,
+    utils::{HashMap, HashSet},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    pointer::{PointerPlugin, PointerSystem, Pointers},
+    storage,
+};
+
+// How far a stick has to be pushed before its action counts as pressed
+const STICK_PRESS_THRESHOLD: f32 = 0.5;
+
+/// Everything a game can ask the player to do. Games only bind the actions they use.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum Action {
+    MoveLeft,
+    MoveRight,
+    MoveUp,
+    MoveDown,
+    Jump,
+    Fire,
+    Hit,
+    Confirm,
+    Retry,
+    NextStage,
+    Pause,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum AxisDirection {
+    Positive,
+    Negative,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButtonType),
+    Stick(GamepadAxisType, AxisDirection),
+    /// Any touch or the left mouse button, wherever it is on the window.
+    Pointer,
+}
+
+impl From<KeyCode> for InputBinding {
+    fn from(key_code: KeyCode) -> Self {
+        InputBinding::Key(key_code)
+    }
+}
+
+impl From<MouseButton> for InputBinding {
+    fn from(mouse_button: MouseButton) -> Self {
+        InputBinding::Mouse(mouse_button)
+    }
+}
+
+impl From<GamepadButtonType> for InputBinding {
+    fn from(button_type: GamepadButtonType) -> Self {
+        InputBinding::Gamepad(button_type)
+    }
+}
+
+impl From<(GamepadAxisType, AxisDirection)> for InputBinding {
+    fn from((axis_type, direction): (GamepadAxisType, AxisDirection)) -> Self {
+        InputBinding::Stick(axis_type, direction)
+    }
+}
+
+impl fmt::Display for InputBinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputBinding::Key(key_code) => {
+                let name = format!("{key_code:?}");
+                write!(f, "{}", name.strip_prefix("Key").unwrap_or(&name))
+            }
+            InputBinding::Mouse(mouse_button) => write!(f, "Mouse {mouse_button:?}"),
+            InputBinding::Gamepad(button_type) => write!(f, "Pad {button_type:?}"),
+            InputBinding::Stick(axis_type, AxisDirection::Positive) => {
+                write!(f, "Pad {axis_type:?}+")
+            }
+            InputBinding::Stick(axis_type, AxisDirection::Negative) => {
+                write!(f, "Pad {axis_type:?}-")
+            }
+            InputBinding::Pointer => write!(f, "Tap"),
+        }
+    }
+}
+
+/// The keys, mouse buttons and gamepad buttons bound to each action of one game.
+#[derive(Resource, Serialize, Deserialize, Clone, Debug)]
+#[serde(bound = "A: Eq + Hash + Serialize + DeserializeOwned")]
+pub struct InputMap<A> {
+    #[serde(skip)]
+    game: String,
+    bindings: HashMap<A, Vec<InputBinding>>,
+}
+
+impl<A: Copy + Eq + Hash + Serialize + DeserializeOwned> InputMap<A> {
+    pub fn new(game: &str) -> Self {
+        Self {
+            game: game.to_string(),
+            bindings: HashMap::default(),
+        }
+    }
+
+    pub fn bind(mut self, action: A, binding: impl Into<InputBinding>) -> Self {
+        self.bindings
+            .entry(action)
+            .or_default()
+            .push(binding.into());
+        self
+    }
+
+    /// Every action with bindings, in no particular order.
+    pub fn actions(&self) -> impl Iterator<Item = A> + '_ {
+        self.bindings.keys().copied()
+    }
+
+    pub fn bindings(&self, action: A) -> &[InputBinding] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// The first binding of `action`, for on-screen prompts.
+    pub fn label(&self, action: A) -> String {
+        self.bindings(action)
+            .first()
+            .map_or_else(|| "-".to_string(), ToString::to_string)
+    }
+
+    pub fn rebind(&mut self, action: A, bindings: Vec<InputBinding>) {
+        self.bindings.insert(action, bindings);
+    }
+
+    fn file_name(&self) -> String {
+        format!("bindings/{}.json", self.game)
+    }
+
+    /// `self` holds the defaults. Actions found in the saved file override them, the rest keep
+    /// their defaults. Without a file the defaults are written out so players can edit them.
+    pub fn load(mut self) -> Self {
+        let Some(contents) = storage::load(&self.file_name()) else {
+            self.save();
+            return self;
+        };
+
+        match serde_json::from_str::<InputMap<A>>(&contents) {
+            Ok(saved) => self.bindings.extend(saved.bindings),
+            Err(error) => warn!("ignoring unreadable bindings for {}: {error}", self.game),
+        }
+        self
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string_pretty(self) {
+            Ok(contents) => storage::save(&self.file_name(), &contents),
+            Err(error) => warn!("failed to serialize bindings for {}: {error}", self.game),
+        }
+    }
+}
+
+/// Tracks `ButtonInput<A>` and `Axis<A>` from the devices bound in `InputMap<A>`, so game systems
+/// read actions exactly like they would read keys. `Axis<A>` holds how far each action is pushed,
+/// from 0.0 to 1.0, which is only ever in between for sticks.
+///
+/// Inside `FixedUpdate` the same `ButtonInput<A>` is seen per fixed tick instead of per frame:
+/// a press is `just_pressed` for exactly one tick, however many ticks a frame runs.
+pub struct InputMapPlugin<A>(PhantomData<A>);
+
+impl<A> Default for InputMapPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: Copy + Eq + Hash + Send + Sync + 'static> Plugin for InputMapPlugin<A>
+where
+    InputMap<A>: Resource,
+{
+    fn build(&self, app: &mut App) {
+        // Shared by every action type, and by games reading pointer positions directly
+        if !app.is_plugin_added::<PointerPlugin>() {
+            app.add_plugins(PointerPlugin);
+        }
+
+        app.init_resource::<ButtonInput<A>>()
+            .init_resource::<Axis<A>>()
+            .init_resource::<FixedActions<A>>()
+            .add_systems(
+                PreUpdate,
+                update_actions::<A>.after(InputSystem).after(PointerSystem),
+            )
+            .add_systems(
+                RunFixedMainLoop,
+                queue_fixed_actions::<A>.before(run_fixed_main_schedule),
+            )
+            .add_systems(FixedPreUpdate, begin_fixed_actions::<A>)
+            .add_systems(FixedPostUpdate, end_fixed_actions::<A>);
+    }
+}
+
+/// The actions of the current fixed tick, swapped with the frame's `ButtonInput<A>` while
+/// `FixedUpdate` runs.
+#[derive(Resource)]
+pub struct FixedActions<A: Copy + Eq + Hash + Send + Sync + 'static> {
+    input: ButtonInput<A>,
+    // Pressed in a frame since the last tick, so the next tick still sees them
+    queued: HashSet<A>,
+}
+
+impl<A: Copy + Eq + Hash + Send + Sync + 'static> Default for FixedActions<A> {
+    fn default() -> Self {
+        Self {
+            input: ButtonInput::default(),
+            queued: HashSet::default(),
+        }
+    }
+}
+
+impl<A: Copy + Eq + Hash + Send + Sync + 'static> FixedActions<A> {
+    /// Forgets presses no tick has seen yet, like `ButtonInput::reset_all`.
+    pub fn reset_all(&mut self) {
+        self.input.reset_all();
+        self.queued.clear();
+    }
+}
+
+/// Every way a press reaches the game, to swallow the one that closed a menu or prompt.
+#[derive(SystemParam)]
+pub struct ResetInput<'w> {
+    keyboard_input: ResMut<'w, ButtonInput<KeyCode>>,
+    gamepad_input: ResMut<'w, ButtonInput<GamepadButton>>,
+    pointers: ResMut<'w, Pointers>,
+    fixed_actions: ResMut<'w, FixedActions<Action>>,
+}
+
+impl ResetInput<'_> {
+    pub fn reset_all(&mut self) {
+        self.keyboard_input.reset_all();
+        self.gamepad_input.reset_all();
+        self.pointers.reset_all();
+        self.fixed_actions.reset_all();
+    }
+}
+
+pub fn update_actions<A: Copy + Eq + Hash + Send + Sync + 'static>(
+    input_map: Option<Res<InputMap<A>>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    gamepads: Res<Gamepads>,
+    gamepad_input: Res<ButtonInput<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    pointers: Res<Pointers>,
+    mut actions: ResMut<ButtonInput<A>>,
+    mut action_axes: ResMut<Axis<A>>,
+) where
+    InputMap<A>: Resource,
+{
+    actions.clear();
+
+    let Some(input_map) = input_map else {
+        return;
+    };
+
+    let button_value = |pressed: bool| if pressed { 1.0 } else { 0.0 };
+
+    for (action, bindings) in &input_map.bindings {
+        let value = bindings
+            .iter()
+            .map(|binding| match *binding {
+                InputBinding::Key(key_code) => button_value(keyboard_input.pressed(key_code)),
+                InputBinding::Mouse(mouse_button) => {
+                    button_value(mouse_input.pressed(mouse_button))
+                }
+                InputBinding::Gamepad(button_type) => {
+                    button_value(gamepads.iter().any(|gamepad| {
+                        gamepad_input.pressed(GamepadButton::new(gamepad, button_type))
+                    }))
+                }
+                InputBinding::Stick(axis_type, direction) => gamepads
+                    .iter()
+                    .filter_map(|gamepad| gamepad_axes.get(GamepadAxis::new(gamepad, axis_type)))
+                    .map(|position| match direction {
+                        AxisDirection::Positive => position,
+                        AxisDirection::Negative => -position,
+                    })
+                    .fold(0.0, f32::max),
+                InputBinding::Pointer => button_value(pointers.any_pressed()),
+            })
+            .fold(0.0, f32::max);
+
+        action_axes.set(*action, value);
+        if value >= STICK_PRESS_THRESHOLD {
+            actions.press(*action);
+        } else {
+            actions.release(*action);
+        }
+    }
+}
+
+pub fn queue_fixed_actions<A: Copy + Eq + Hash + Send + Sync + 'static>(
+    actions: Res<ButtonInput<A>>,
+    mut fixed_actions: ResMut<FixedActions<A>>,
+) {
+    fixed_actions
+        .queued
+        .extend(actions.get_just_pressed().copied());
+}
+
+fn begin_fixed_actions<A: Copy + Eq + Hash + Send + Sync + 'static>(
+    mut actions: ResMut<ButtonInput<A>>,
+    mut fixed_actions: ResMut<FixedActions<A>>,
+) {
+    let FixedActions { input, queued } = &mut *fixed_actions;
+
+    input.clear();
+    for action in queued.drain() {
+        // Pressed again before the tick saw it released
+        input.release(action);
+        input.press(action);
+    }
+    let released: Vec<A> = input
+        .get_pressed()
+        .filter(|action| !input.just_pressed(**action) && !actions.pressed(**action))
+        .copied()
+        .collect();
+    for action in released {
+        input.release(action);
+    }
+    for action in actions.get_pressed() {
+        input.press(*action);
+    }
+
+    std::mem::swap(&mut *actions, input);
+}
+
+fn end_fixed_actions<A: Copy + Eq + Hash + Send + Sync + 'static>(
+    mut actions: ResMut<ButtonInput<A>>,
+    mut fixed_actions: ResMut<FixedActions<A>>,
+) {
+    std::mem::swap(&mut *actions, &mut fixed_actions.input);
+}
+
+/// Signed strength of a pair of opposite actions, e.g. `MoveLeft`/`MoveRight` as -1.0..=1.0.
+pub fn action_axis<A: Copy + Eq + Hash>(action_axes: &Axis<A>, negative: A, positive: A) -> f32 {
+    action_axes.get(positive).unwrap_or(0.0) - action_axes.get(negative).unwrap_or(0.0)
+}
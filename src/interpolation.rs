@@ -0,0 +1,78 @@
+use bevy::{
+    app::RunFixedMainLoop, prelude::*, time::run_fixed_main_schedule, transform::TransformSystem,
+};
+
+/// Draws an entity moved in `FixedUpdate` between its positions of the last two ticks, so motion
+/// looks smooth whether a frame runs no tick or several.
+///
+/// Moving it outside `FixedUpdate`, e.g. by dragging in `Update`, places it there directly.
+#[derive(Component, Default, Clone, Copy, Debug)]
+pub struct Interpolated {
+    previous: Option<Vec3>,
+    current: Option<Vec3>,
+    rendered: Option<Vec3>,
+}
+
+#[derive(SystemSet, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct InterpolationSet;
+
+pub struct InterpolationPlugin;
+
+impl Plugin for InterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            RunFixedMainLoop,
+            restore_translation.before(run_fixed_main_schedule),
+        )
+        .add_systems(FixedLast, store_translation)
+        .add_systems(
+            PostUpdate,
+            interpolate_translation
+                .in_set(InterpolationSet)
+                .before(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+// Puts the simulated position back before the next tick builds on it
+fn restore_translation(mut query: Query<(&mut Transform, &mut Interpolated)>) {
+    for (mut transform, mut interpolated) in &mut query {
+        let Some(current) = interpolated.current else {
+            continue;
+        };
+
+        if interpolated.rendered == Some(transform.translation) {
+            transform.translation = current;
+        } else {
+            interpolated.previous = Some(transform.translation);
+            interpolated.current = Some(transform.translation);
+        }
+    }
+}
+
+fn store_translation(mut query: Query<(&Transform, &mut Interpolated)>) {
+    for (transform, mut interpolated) in &mut query {
+        interpolated.previous = interpolated.current.or(Some(transform.translation));
+        interpolated.current = Some(transform.translation);
+    }
+}
+
+fn interpolate_translation(
+    mut query: Query<(&mut Transform, &mut Interpolated)>,
+    fixed_time: Res<Time<Fixed>>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+
+    for (mut transform, mut interpolated) in &mut query {
+        match (interpolated.previous, interpolated.current) {
+            (Some(previous), Some(current)) if current == transform.translation => {
+                transform.translation = previous.lerp(current, alpha);
+            }
+            _ => {
+                interpolated.previous = Some(transform.translation);
+                interpolated.current = Some(transform.translation);
+            }
+        }
+        interpolated.rendered = Some(transform.translation);
+    }
+}
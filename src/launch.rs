@@ -0,0 +1,31 @@
+/// A value the game was launched with: `--<name> <value>` or `--<name>=<value>` on the command
+/// line, then the `GAME_<NAME>` environment variable, then `?<name>=<value>` in the URL on wasm.
+pub fn option(name: &str) -> Option<String> {
+    let flag = format!("--{name}");
+    let args: Vec<String> = std::env::args().collect();
+    let from_args = args
+        .iter()
+        .enumerate()
+        .find_map(|(i, arg)| match arg.strip_prefix(&flag) {
+            Some("") => args.get(i + 1).cloned(),
+            Some(value) => value.strip_prefix('=').map(str::to_string),
+            None => None,
+        });
+
+    from_args
+        .or_else(|| std::env::var(format!("GAME_{}", name.to_uppercase())).ok())
+        .or_else(|| query_option(name))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn query_option(_name: &str) -> Option<String> {
+    None
+}
+
+#[cfg(target_arch = "wasm32")]
+fn query_option(name: &str) -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+    web_sys::UrlSearchParams::new_with_str(&search)
+        .ok()?
+        .get(name)
+}
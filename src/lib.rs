@@ -0,0 +1,321 @@
+#![allow(clippy::type_complexity, clippy::too_many_arguments)]
+
+use bevy::prelude::*;
+
+use crate::{
+    collision::{CollisionPlugin, CollisionSet},
+    high_scores::{HighScorePlugin, ScoreOrder},
+    input::{Action, FixedActions, InputMapPlugin},
+    interpolation::InterpolationPlugin,
+    loading::LoadingPlugin,
+    locale::{Locale, LocalePlugin, LocalizedString, LocalizedText},
+    menu::{spawn_menu_buttons, Menu, MenuAction, MenuButton, MenuChosen, MenuPlugin, MenuSet},
+    music::{Music, MusicPlugin},
+    pause_menu::PauseMenuPlugin,
+    play_area::PlayAreaPlugin,
+    pointer::{PointerPlugin, Pointers},
+    replay::ReplayPlugin,
+    result_screen::ResultScreenPlugin,
+    rng::GameRng,
+    settings::{SettingsPlugin, SettingsScreen},
+    sfx::SfxPlugin,
+    theme::{Theme, ThemePlugin},
+};
+
+pub mod arcade;
+pub mod collision;
+pub mod debug_overlay;
+pub mod games;
+pub mod high_scores;
+pub mod input;
+pub mod interpolation;
+pub mod launch;
+pub mod loading;
+pub mod locale;
+pub mod menu;
+pub mod mini_game;
+pub mod music;
+pub mod pause_menu;
+pub mod play_area;
+pub mod pointer;
+pub mod replay;
+pub mod result_screen;
+pub mod rng;
+pub mod settings;
+pub mod sfx;
+pub mod spatial_hash;
+pub mod storage;
+pub mod theme;
+pub mod tuning;
+
+const PRESSANYKEY_FONT_SIZE: f32 = 40.0;
+const PRESSANYKEY_TEXT_PADDING: Val = Val::Px(20.0);
+const PRESSANYKEY_COLOR: Color = Color::srgb(0.5, 0.5, 0.5);
+
+/// The game being played, by `mini_game::MiniGameInfo::id`. The arcade's hub plays none, a
+/// standalone game starts in its own.
+#[derive(States, Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ActiveGame(pub Option<&'static str>);
+
+/// Where the active game is at, from loading its assets to its result. Only exists while a game
+/// is active.
+#[derive(SubStates, Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+#[source(ActiveGame = ActiveGame(Some(_)))]
+pub enum AppState {
+    #[default]
+    Loading,
+    MainMenu,
+    InGame,
+    Paused,
+    GameOver,
+    GameClear,
+}
+
+/// Identifies the running game, e.g. to pick its high-score table.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct CurrentGame {
+    pub id: &'static str,
+    pub score_order: ScoreOrder,
+}
+
+#[derive(Component)]
+pub struct PressAnyKey;
+
+/// A button of a game's title screen, which any other key or tap starts as well.
+#[derive(Component, Clone, Copy, Eq, PartialEq, Debug)]
+pub enum TitleButton {
+    Start,
+    Settings,
+}
+
+impl MenuButton for TitleButton {
+    fn label(self) -> LocalizedString {
+        match self {
+            TitleButton::Start => "start",
+            TitleButton::Settings => "settings",
+        }
+        .into()
+    }
+}
+
+#[derive(Component)]
+pub struct TitlePanel;
+
+/// Set by the pause menu's Restart, so leaving `Paused` starts the run over instead of resuming.
+#[derive(Resource, Default, Debug)]
+pub struct Restarting(pub bool);
+
+/// Units per second, integrated every fixed tick.
+#[derive(Component, Deref, DerefMut, Default, Clone, Copy, Debug)]
+pub struct Velocity(pub Vec3);
+
+/// Each game's simulation in `FixedUpdate`. It reacts to input and to the contacts of the last
+/// tick, then velocities are integrated and new contacts detected.
+#[derive(SystemSet, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct GameplaySet;
+
+/// Shared state machine, asset loading, title-screen prompt, pause menu, settings, sound
+/// effects, music and velocity integration for every mini-game.
+pub struct MiniGamePlugin;
+
+impl Plugin for MiniGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<ActiveGame>()
+            .add_sub_state::<AppState>()
+            .insert_resource(GameRng::from_env())
+            .init_resource::<Restarting>()
+            .add_plugins(CollisionPlugin)
+            .add_plugins(HighScorePlugin)
+            .add_plugins(PointerPlugin)
+            .add_plugins(PlayAreaPlugin)
+            .add_plugins(InputMapPlugin::<Action>::default())
+            .add_plugins(InterpolationPlugin)
+            .add_plugins(ReplayPlugin)
+            .add_plugins(ResultScreenPlugin)
+            .add_plugins(PauseMenuPlugin)
+            .add_plugins(SettingsPlugin)
+            .add_plugins(LocalePlugin)
+            .add_plugins(ThemePlugin)
+            .add_plugins(LoadingPlugin)
+            .add_plugins(SfxPlugin)
+            .add_plugins(MusicPlugin)
+            .configure_sets(FixedUpdate, GameplaySet.before(apply_velocity))
+            // Before the games set up the new run on entering `InGame`
+            .add_systems(OnExit(AppState::MainMenu), reset_rng)
+            .add_systems(OnExit(AppState::GameOver), reset_rng)
+            .add_systems(OnExit(AppState::GameClear), reset_rng)
+            .add_plugins(MenuPlugin::<TitleButton>::default())
+            .add_systems(
+                OnEnter(AppState::MainMenu),
+                (spawn_press_any_key, spawn_title_menu),
+            )
+            .add_systems(
+                Update,
+                press_any_key
+                    .after(MenuSet)
+                    .run_if(in_state(AppState::MainMenu))
+                    .run_if(in_state(SettingsScreen::Closed)),
+            )
+            .add_systems(OnExit(AppState::MainMenu), despawn_title_menu)
+            // The settings cover the title screen until they close
+            .add_systems(
+                OnEnter(SettingsScreen::Open),
+                despawn_title_menu.run_if(in_state(AppState::MainMenu)),
+            )
+            .add_systems(
+                OnExit(SettingsScreen::Open),
+                return_from_settings.run_if(in_state(AppState::MainMenu)),
+            )
+            .add_systems(
+                FixedUpdate,
+                apply_velocity
+                    .before(CollisionSet)
+                    .run_if(in_state(AppState::InGame)),
+            );
+
+        #[cfg(feature = "debug-overlay")]
+        app.add_plugins(debug_overlay::DebugOverlayPlugin);
+    }
+}
+
+/// True while `InGame` is entered from `Paused`, so set-up meant for a fresh run can be skipped.
+pub fn resuming(
+    mut transitions: EventReader<StateTransitionEvent<AppState>>,
+    restarting: Res<Restarting>,
+) -> bool {
+    !restarting.0
+        && transitions
+            .read()
+            .last()
+            .is_some_and(|transition| transition.exited == Some(AppState::Paused))
+}
+
+pub fn spawn_press_any_key(mut commands: Commands, locale: Res<Locale>) {
+    commands.spawn((
+        TextBundle::from_section(
+            locale.get("press_any_key"),
+            locale.text_style(PRESSANYKEY_FONT_SIZE, PRESSANYKEY_COLOR),
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: PRESSANYKEY_TEXT_PADDING,
+            right: PRESSANYKEY_TEXT_PADDING,
+            ..default()
+        }),
+        PressAnyKey,
+        LocalizedText::new("press_any_key"),
+    ));
+}
+
+fn spawn_title_menu(mut commands: Commands, locale: Res<Locale>, theme: Res<Theme>) {
+    spawn_title_panel(&mut commands, &locale, &theme, None);
+}
+
+fn return_from_settings(mut commands: Commands, locale: Res<Locale>, theme: Res<Theme>) {
+    spawn_title_panel(&mut commands, &locale, &theme, Some(TitleButton::Settings));
+}
+
+fn spawn_title_panel(
+    commands: &mut Commands,
+    locale: &Locale,
+    theme: &Theme,
+    selected: Option<TitleButton>,
+) {
+    let menu = Menu::new(vec![TitleButton::Start, TitleButton::Settings], selected);
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    bottom: PRESSANYKEY_TEXT_PADDING,
+                    left: PRESSANYKEY_TEXT_PADDING,
+                    ..default()
+                },
+                ..default()
+            },
+            TitlePanel,
+        ))
+        .with_children(|parent| {
+            spawn_menu_buttons(parent, menu.buttons(), FlexDirection::Row, locale, theme);
+        });
+
+    commands.insert_resource(menu);
+}
+
+fn despawn_title_menu(mut commands: Commands, panel_query: Query<Entity, With<TitlePanel>>) {
+    for panel_entity in &panel_query {
+        commands.entity(panel_entity).despawn_recursive();
+    }
+    commands.remove_resource::<Menu<TitleButton>>();
+}
+
+pub fn press_any_key(
+    pressanykey_query: Query<Entity, With<PressAnyKey>>,
+    mut commands: Commands,
+    mut chosen_events: EventReader<MenuChosen<TitleButton>>,
+    menu_input: Res<ButtonInput<MenuAction>>,
+    interaction_query: Query<&Interaction, With<TitleButton>>,
+    mut app_state: ResMut<NextState<AppState>>,
+    mut settings_screen: ResMut<NextState<SettingsScreen>>,
+    mut inkey: ResMut<ButtonInput<KeyCode>>,
+    mut gamepad_input: ResMut<ButtonInput<GamepadButton>>,
+    mut pointers: ResMut<Pointers>,
+    mut fixed_actions: ResMut<FixedActions<Action>>,
+) {
+    let chosen = chosen_events
+        .read()
+        .last()
+        .map(|MenuChosen(button)| *button);
+    if chosen == Some(TitleButton::Settings) {
+        settings_screen.set(SettingsScreen::Open);
+        return;
+    }
+
+    // Not keyboard events, which outlive the menu press that led to this screen. Presses that
+    // move through the title menu or land on its buttons are the menu's.
+    let on_menu = menu_input.get_just_pressed().next().is_some();
+    let on_button = interaction_query
+        .iter()
+        .any(|interaction| *interaction != Interaction::None);
+    let pressed = chosen == Some(TitleButton::Start)
+        || (!on_menu
+            && (inkey.get_just_pressed().next().is_some()
+                || gamepad_input.get_just_pressed().next().is_some()))
+        || (!on_button && pointers.any_just_pressed());
+
+    if !pressed {
+        return;
+    }
+
+    for pressanykey_entity in &pressanykey_query {
+        commands.entity(pressanykey_entity).despawn_recursive();
+    }
+
+    app_state.set(AppState::InGame);
+    // Keep the key that dismissed the prompt from reaching the game
+    inkey.reset_all();
+    gamepad_input.reset_all();
+    pointers.reset_all();
+    fixed_actions.reset_all();
+}
+
+// Whatever the run spawned goes, cameras, windows and music outlive it
+pub fn despawn_run(
+    mut commands: Commands,
+    entities: Query<Entity, (Without<Camera>, Without<Window>, Without<Music>)>,
+) {
+    for entity in &entities {
+        commands.entity(entity).despawn();
+    }
+}
+
+// Every run replays the seed from the start, so a retry is the same game again
+pub fn reset_rng(mut rng: ResMut<GameRng>) {
+    rng.reset();
+}
+
+pub fn apply_velocity(mut query: Query<(&mut Transform, &Velocity)>, time: Res<Time>) {
+    for (mut transform, velocity) in &mut query {
+        transform.translation += velocity.0 * time.delta_seconds();
+    }
+}
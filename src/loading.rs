@@ -0,0 +1,522 @@
+use std::path::Path;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::music::MusicPlayer;
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::asset::io::file::FileAssetReader;
+use bevy::{
+    asset::{AssetPath, LoadState, RecursiveDependencyLoadState, UntypedAssetId},
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{
+    locale::{Language, Locale, LocalizedText},
+    mini_game::MiniGames,
+    music::GameMusic,
+    sfx::SfxEvent,
+    theme::Theme,
+    AppState,
+};
+
+pub(crate) const TEXT_FONT: &str = "fonts/FiraSans-Bold.ttf";
+// Noto Sans JP (SIL Open Font License), a subset with the kana and kanji the texts use is enough
+pub(crate) const JAPANESE_FONT: &str = "fonts/NotoSansJP-Bold.ttf";
+const MONO_FONT: &str = "fonts/FiraMono-Medium.ttf";
+
+const LOADING_PADDING: Val = Val::Px(40.0);
+const LOADING_GAP: Val = Val::Px(20.0);
+const LOADING_FONT_SIZE: f32 = 40.0;
+const LOADING_FONT_SIZE_SMALL: f32 = 18.0;
+const PROGRESS_BAR_SIZE: Vec2 = Vec2::new(400.0, 20.0);
+
+// Half a tile, loud enough to be noticed as missing
+const PLACEHOLDER_SIZE: f32 = 0.5;
+const PLACEHOLDER_COLOR: Color = Color::srgb(1.0, 0.0, 1.0);
+
+/// The assets a game needs before its title screen, on top of the `UiFonts` and `GameSounds`
+/// every game shares. Paths are under `assets`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AssetManifest {
+    /// glTF scenes, with their `#Scene0` label.
+    pub models: &'static [&'static str],
+}
+
+impl AssetManifest {
+    pub const EMPTY: AssetManifest = AssetManifest { models: &[] };
+}
+
+/// The fonts of the texts every game shows.
+#[derive(Resource, Clone, Debug)]
+pub struct UiFonts {
+    /// The `Locale`'s English font.
+    pub text: Handle<Font>,
+    /// The `Locale`'s Japanese font.
+    pub japanese: Handle<Font>,
+    /// For numbers that should line up, like scores and timers.
+    pub mono: Handle<Font>,
+}
+
+impl FromWorld for UiFonts {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        Self {
+            text: asset_server.load(TEXT_FONT),
+            japanese: asset_server.load(JAPANESE_FONT),
+            mono: asset_server.load(MONO_FONT),
+        }
+    }
+}
+
+/// Every sound an `SfxEvent` plays, by path.
+#[derive(Resource, Clone, Debug)]
+pub struct GameSounds(HashMap<&'static str, Handle<AudioSource>>);
+
+impl GameSounds {
+    pub fn get(&self, path: &str) -> Handle<AudioSource> {
+        self.0
+            .get(path)
+            .cloned()
+            .unwrap_or_else(|| panic!("{path} is not a sound of any SfxEvent"))
+    }
+}
+
+impl FromWorld for GameSounds {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        Self(
+            SfxEvent::ALL
+                .iter()
+                .map(|event| event.sound().path)
+                .map(|path| (path, asset_server.load(path)))
+                .collect(),
+        )
+    }
+}
+
+/// The models in the `AssetManifest` of the active game, by path. One that fails to load is
+/// swapped for a placeholder cube.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct GameModels {
+    handles: HashMap<&'static str, Handle<Scene>>,
+    placeholders: Vec<&'static str>,
+}
+
+impl GameModels {
+    pub fn new(manifest: &AssetManifest, asset_server: &AssetServer) -> Self {
+        Self {
+            handles: manifest
+                .models
+                .iter()
+                .map(|path| (*path, asset_server.load(*path)))
+                .collect(),
+            placeholders: Vec::new(),
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Handle<Scene> {
+        self.handles
+            .get(path)
+            .cloned()
+            .unwrap_or_else(|| panic!("{path} is not in the game's AssetManifest"))
+    }
+
+    pub fn is_placeholder(&self, path: &str) -> bool {
+        self.placeholders.contains(&path)
+    }
+}
+
+/// What stands in for a referenced file that is missing.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Fallback {
+    /// Nothing, loading stops on the list of what failed.
+    None,
+    /// The texts of the font's language are shown in English.
+    EnglishFont,
+    /// A placeholder cube.
+    PlaceholderModel,
+    /// Nothing, its music is never loaded and stays silent.
+    Silence,
+}
+
+/// A file under `assets` that the games load.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct ReferencedAsset {
+    /// Without a `#` label.
+    pub path: &'static str,
+    pub fallback: Fallback,
+}
+
+/// Every file the fonts, sounds, hub music and `MiniGames` load, each once.
+pub fn referenced_assets(mini_games: &MiniGames) -> Vec<ReferencedAsset> {
+    let required = [TEXT_FONT, MONO_FONT]
+        .into_iter()
+        .chain(SfxEvent::ALL.iter().map(|event| event.sound().path))
+        .map(|path| (path, Fallback::None));
+    let fonts = Language::ALL
+        .into_iter()
+        .filter(|language| *language != Language::English)
+        .map(|language| (language.font(), Fallback::EnglishFont));
+    let models = mini_games
+        .iter()
+        .flat_map(|info| info.assets.models)
+        .map(|path| (*path, Fallback::PlaceholderModel));
+    let music = mini_games
+        .iter()
+        .flat_map(|info| info.music.paths())
+        .chain([GameMusic::DEFAULT.title])
+        .map(|path| (path, Fallback::Silence));
+
+    let mut assets: Vec<ReferencedAsset> = Vec::new();
+    for (path, fallback) in required.chain(fonts).chain(models).chain(music) {
+        let path = path.split_once('#').map_or(path, |(file, _)| file);
+        if !assets.iter().any(|asset| asset.path == path) {
+            assets.push(ReferencedAsset { path, fallback });
+        }
+    }
+    assets
+}
+
+/// The `referenced_assets` that aren't under `root`, the `assets` folder.
+pub fn missing_assets(root: &Path, mini_games: &MiniGames) -> Vec<ReferencedAsset> {
+    referenced_assets(mini_games)
+        .into_iter()
+        .filter(|asset| !root.join(asset.path).is_file())
+        .collect()
+}
+
+/// How far the `UiFonts`, `GameSounds` and `GameModels` have loaded, updated every frame.
+#[derive(Resource, Default, Debug)]
+pub struct LoadingProgress {
+    pub loaded: usize,
+    pub total: usize,
+    /// The path and error of every asset that failed to load.
+    pub failed: Vec<(String, String)>,
+}
+
+impl LoadingProgress {
+    pub fn is_done(&self) -> bool {
+        self.loaded == self.total
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.loaded as f32 / self.total as f32
+        }
+    }
+}
+
+#[derive(Component)]
+struct LoadingScreen;
+
+#[derive(Component)]
+struct ProgressFill;
+
+#[derive(Component)]
+struct LoadingErrors;
+
+/// Holds every game in `AppState::Loading` until its assets have loaded, with a progress bar,
+/// then moves on to its title screen. An asset that fails to load stops it there, on a screen
+/// listing what failed and why.
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiFonts>()
+            .init_resource::<GameSounds>()
+            .init_resource::<LoadingProgress>()
+            .add_systems(OnEnter(AppState::Loading), spawn_loading_screen)
+            .add_systems(OnExit(AppState::Loading), despawn_loading_screen)
+            .add_systems(
+                Update,
+                (
+                    use_placeholders,
+                    track_loading,
+                    (show_progress, show_errors, finish_loading)
+                        .run_if(in_state(AppState::Loading)),
+                )
+                    .chain(),
+            );
+
+        // Wasm fetches its assets, so there is no folder to look in
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(asset_plugin) = app.get_added_plugins::<AssetPlugin>().first() {
+            let reader = FileAssetReader::new(&asset_plugin.file_path);
+            app.insert_resource(AssetFolder(reader.root_path().clone()))
+                // Before the hub and the first game pick their music
+                .add_systems(PreStartup, check_assets);
+        }
+    }
+}
+
+// Where the `AssetPlugin` reads from
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Resource)]
+struct AssetFolder(std::path::PathBuf);
+
+#[cfg(not(target_arch = "wasm32"))]
+fn check_assets(
+    folder: Res<AssetFolder>,
+    mini_games: Res<MiniGames>,
+    mut music_player: ResMut<MusicPlayer>,
+) {
+    for asset in missing_assets(&folder.0, &mini_games) {
+        let path = asset.path;
+        match asset.fallback {
+            Fallback::None => error!("{path} is missing, loading will stop on it"),
+            Fallback::EnglishFont => warn!("{path} is missing, its language is shown in English"),
+            Fallback::PlaceholderModel => {
+                warn!("{path} is missing, a placeholder is shown instead")
+            }
+            // No music ships, so this is the usual case
+            Fallback::Silence => music_player.mark_missing(path),
+        }
+    }
+}
+
+// Its own scene of one cube, built the first time a model fails
+fn use_placeholders(
+    asset_server: Res<AssetServer>,
+    models: Option<ResMut<GameModels>>,
+    mut placeholder: Local<Option<Handle<Scene>>>,
+    mut scenes: ResMut<Assets<Scene>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(mut models) = models else {
+        return;
+    };
+    let failed: Vec<(&'static str, String)> = models
+        .handles
+        .iter()
+        .filter(|(path, _)| !models.is_placeholder(path))
+        .filter_map(|(path, handle)| {
+            load_error(&asset_server, handle.id().untyped()).map(|error| (*path, error))
+        })
+        .collect();
+
+    for (path, error) in failed {
+        warn!("Couldn't load {path}, a placeholder is shown instead: {error}");
+        let placeholder = placeholder
+            .get_or_insert_with(|| {
+                let mut world = World::new();
+                world.spawn(PbrBundle {
+                    mesh: meshes.add(Cuboid::from_length(PLACEHOLDER_SIZE)),
+                    material: materials.add(PLACEHOLDER_COLOR),
+                    transform: Transform::from_xyz(0.0, PLACEHOLDER_SIZE / 2.0, 0.0),
+                    ..default()
+                });
+                scenes.add(Scene::new(world))
+            })
+            .clone();
+        models.handles.insert(path, placeholder);
+        models.placeholders.push(path);
+    }
+}
+
+fn track_loading(
+    asset_server: Res<AssetServer>,
+    fonts: Res<UiFonts>,
+    sounds: Res<GameSounds>,
+    models: Option<Res<GameModels>>,
+    mut progress: ResMut<LoadingProgress>,
+) {
+    let mut ids: Vec<UntypedAssetId> = vec![fonts.text.id().untyped(), fonts.mono.id().untyped()];
+    // Without it Japanese is shown in English, which is already waited on
+    if !matches!(
+        asset_server.load_state(&fonts.japanese),
+        LoadState::Failed(_)
+    ) {
+        ids.push(fonts.japanese.id().untyped());
+    }
+    ids.extend(sounds.0.values().map(|handle| handle.id().untyped()));
+    // Placeholders are built, not loaded
+    let mut placeholders = 0;
+    if let Some(models) = &models {
+        placeholders = models.placeholders.len();
+        ids.extend(
+            models
+                .handles
+                .iter()
+                .filter(|(path, _)| !models.is_placeholder(path))
+                .map(|(_, handle)| handle.id().untyped()),
+        );
+    }
+
+    let total = ids.len() + placeholders;
+    let mut loaded = placeholders;
+    let mut failed = Vec::new();
+    for id in &ids {
+        if asset_server.is_loaded_with_dependencies(*id) {
+            loaded += 1;
+        } else if let Some(error) = load_error(&asset_server, *id) {
+            let path = asset_server
+                .get_path(*id)
+                .map_or_else(String::new, |path| path.to_string());
+            failed.push((path, error));
+        }
+    }
+
+    if progress.loaded != loaded || progress.total != total || progress.failed != failed {
+        *progress = LoadingProgress {
+            loaded,
+            total,
+            failed,
+        };
+    }
+}
+
+fn load_error(asset_server: &AssetServer, id: UntypedAssetId) -> Option<String> {
+    if let LoadState::Failed(error) = asset_server.load_state(id) {
+        return Some(error.to_string());
+    }
+    // A file that doesn't parse only fails itself, not the labeled assets asked for in it
+    let path = asset_server.get_path(id)?;
+    if path.label().is_some() {
+        let root: AssetPath = path.without_label();
+        if let Some(LoadState::Failed(error)) = asset_server
+            .get_path_id(root)
+            .map(|root| asset_server.load_state(root))
+        {
+            return Some(error.to_string());
+        }
+    }
+    if asset_server.recursive_dependency_load_state(id) == RecursiveDependencyLoadState::Failed {
+        return Some("a file it depends on failed to load".to_string());
+    }
+    None
+}
+
+fn spawn_loading_screen(mut commands: Commands, locale: Res<Locale>, theme: Res<Theme>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    padding: UiRect::all(LOADING_PADDING),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: LOADING_GAP,
+                    ..default()
+                },
+                // The high score table's overlay, without what shows through it
+                background_color: theme.overlay.with_alpha(1.0).into(),
+                ..default()
+            },
+            LoadingScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    locale.get("loading"),
+                    locale.text_style(LOADING_FONT_SIZE, theme.light_text),
+                ),
+                LocalizedText::new("loading"),
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(PROGRESS_BAR_SIZE.x),
+                        height: Val::Px(PROGRESS_BAR_SIZE.y),
+                        ..default()
+                    },
+                    background_color: theme.progress_bar.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(0.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            background_color: theme.progress_fill.into(),
+                            ..default()
+                        },
+                        ProgressFill,
+                    ));
+                });
+        });
+}
+
+fn despawn_loading_screen(
+    mut commands: Commands,
+    screen_query: Query<Entity, With<LoadingScreen>>,
+) {
+    for entity in &screen_query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn show_progress(
+    progress: Res<LoadingProgress>,
+    mut fill_query: Query<&mut Style, With<ProgressFill>>,
+) {
+    for mut style in &mut fill_query {
+        style.width = Val::Percent(progress.fraction() * 100.0);
+    }
+}
+
+// Listed again whenever another asset fails
+fn show_errors(
+    mut commands: Commands,
+    progress: Res<LoadingProgress>,
+    locale: Res<Locale>,
+    theme: Res<Theme>,
+    fonts: Res<UiFonts>,
+    screen_query: Query<Entity, With<LoadingScreen>>,
+    errors_query: Query<Entity, With<LoadingErrors>>,
+) {
+    if progress.failed.is_empty() || (!progress.is_changed() && !errors_query.is_empty()) {
+        return;
+    }
+    let Ok(screen) = screen_query.get_single() else {
+        return;
+    };
+    for entity in &errors_query {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    for (path, error) in &progress.failed {
+        error!("Couldn't load {path}: {error}");
+    }
+    let list = progress
+        .failed
+        .iter()
+        .map(|(path, error)| format!("{path}\n  {error}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    commands.entity(screen).with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_section(
+                locale.get("loading_failed"),
+                locale.text_style(LOADING_FONT_SIZE_SMALL, theme.error),
+            ),
+            LocalizedText::new("loading_failed"),
+            LoadingErrors,
+        ));
+        parent.spawn((
+            TextBundle::from_section(
+                list,
+                TextStyle {
+                    font: fonts.mono.clone(),
+                    font_size: LOADING_FONT_SIZE_SMALL,
+                    color: theme.light_text,
+                },
+            ),
+            LoadingErrors,
+        ));
+    });
+}
+
+fn finish_loading(progress: Res<LoadingProgress>, mut app_state: ResMut<NextState<AppState>>) {
+    if progress.is_done() {
+        app_state.set(AppState::MainMenu);
+    }
+}
@@ -0,0 +1,259 @@
+use bevy::{asset::LoadState, prelude::*, ui::widget::measure_text_system, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    loading::{JAPANESE_FONT, TEXT_FONT},
+    mini_game::Localized,
+    settings::Settings,
+};
+
+/// A language the texts are shown in, each with a catalog under `assets/locale` and a font that
+/// has its glyphs.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    Japanese,
+    English,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Japanese, Language::English];
+
+    /// The catalog key of its name.
+    pub fn label(self) -> &'static str {
+        match self {
+            Language::Japanese => "language_japanese",
+            Language::English => "language_english",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|language| *language == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    // Built in, so every string is there from the first frame, also on wasm
+    fn catalog(self) -> &'static str {
+        match self {
+            Language::Japanese => include_str!("../assets/locale/ja.ron"),
+            Language::English => include_str!("../assets/locale/en.ron"),
+        }
+    }
+
+    // FiraSans has no Japanese glyphs
+    pub fn font(self) -> &'static str {
+        match self {
+            Language::Japanese => JAPANESE_FONT,
+            Language::English => TEXT_FONT,
+        }
+    }
+}
+
+/// The strings and font of the language in the `Settings`. A language whose font is missing is
+/// shown in English instead, and keys missing from its catalog too.
+#[derive(Resource, Debug)]
+pub struct Locale {
+    language: Language,
+    catalogs: HashMap<Language, HashMap<String, String>>,
+    fonts: HashMap<Language, Handle<Font>>,
+    missing_fonts: Vec<Language>,
+}
+
+impl FromWorld for Locale {
+    fn from_world(world: &mut World) -> Self {
+        let language = world
+            .get_resource::<Settings>()
+            .map_or_else(Language::default, |settings| settings.language);
+        let asset_server = world.resource::<AssetServer>();
+
+        Self {
+            language,
+            catalogs: Language::ALL
+                .into_iter()
+                .map(|language| (language, parse_catalog(language)))
+                .collect(),
+            fonts: Language::ALL
+                .into_iter()
+                .map(|language| (language, asset_server.load(language.font())))
+                .collect(),
+            missing_fonts: Vec::new(),
+        }
+    }
+}
+
+impl Locale {
+    /// The language texts are shown in, which may differ from the one in the `Settings`.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// The string of `key`, or `key` itself if no catalog has it.
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        [self.language, Language::English]
+            .iter()
+            .find_map(|language| self.catalogs[language].get(key))
+            .map_or(key, String::as_str)
+    }
+
+    /// The text of a string given in every language, like a game's `MiniGameInfo`.
+    pub fn pick(&self, text: Localized) -> &'static str {
+        match self.language {
+            Language::Japanese => text.ja,
+            Language::English => text.en,
+        }
+    }
+
+    /// The string of a `LocalizedString`, through `get` or `pick`.
+    pub fn text(&self, string: LocalizedString) -> &str {
+        match string {
+            LocalizedString::Key(key) => self.get(key),
+            LocalizedString::Inline(text) => self.pick(text),
+        }
+    }
+
+    pub fn font(&self) -> Handle<Font> {
+        self.fonts[&self.language].clone()
+    }
+
+    /// A style in the font of the language.
+    pub fn text_style(&self, font_size: f32, color: Color) -> TextStyle {
+        TextStyle {
+            font: self.font(),
+            font_size,
+            color,
+        }
+    }
+
+    fn shown_language(&self, language: Language) -> Language {
+        if self.missing_fonts.contains(&language) {
+            Language::English
+        } else {
+            language
+        }
+    }
+}
+
+fn parse_catalog(language: Language) -> HashMap<String, String> {
+    ron::from_str(language.catalog())
+        .unwrap_or_else(|error| panic!("invalid {language:?} catalog: {error}"))
+}
+
+/// A string by catalog key, or given in every language.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum LocalizedString {
+    Key(&'static str),
+    Inline(Localized),
+}
+
+impl From<&'static str> for LocalizedString {
+    fn from(key: &'static str) -> Self {
+        LocalizedString::Key(key)
+    }
+}
+
+impl From<Localized> for LocalizedString {
+    fn from(text: Localized) -> Self {
+        LocalizedString::Inline(text)
+    }
+}
+
+/// Keeps sections of a `Text` in the language of the `Locale`, the first one by default. The
+/// sections in between, e.g. a scoreboard's values, are left as they are.
+#[derive(Component, Clone, Debug)]
+pub struct LocalizedText {
+    sections: Vec<(usize, LocalizedString)>,
+}
+
+impl LocalizedText {
+    pub fn new(text: impl Into<LocalizedString>) -> Self {
+        Self {
+            sections: vec![(0, text.into())],
+        }
+    }
+
+    pub fn with_section(mut self, index: usize, text: impl Into<LocalizedString>) -> Self {
+        self.sections.push((index, text.into()));
+        self
+    }
+}
+
+#[derive(SystemSet, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct LocaleSet;
+
+/// Switches the `Locale` with the language in the `Settings` and updates every `LocalizedText`
+/// before the UI is laid out.
+pub struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Locale>().add_systems(
+            PostUpdate,
+            (
+                change_language.run_if(resource_changed::<Settings>),
+                fall_back_from_missing_fonts,
+                localize_texts,
+            )
+                .chain()
+                .in_set(LocaleSet)
+                .before(measure_text_system),
+        );
+    }
+}
+
+fn change_language(settings: Res<Settings>, mut locale: ResMut<Locale>) {
+    let language = locale.shown_language(settings.language);
+    if locale.language != language {
+        locale.language = language;
+    }
+}
+
+fn fall_back_from_missing_fonts(
+    asset_server: Res<AssetServer>,
+    settings: Option<Res<Settings>>,
+    mut locale: ResMut<Locale>,
+) {
+    let missing: Vec<Language> = locale
+        .fonts
+        .iter()
+        .filter(|(language, font)| {
+            !locale.missing_fonts.contains(language)
+                && matches!(asset_server.load_state(font.id()), LoadState::Failed(_))
+        })
+        .map(|(language, _)| *language)
+        .collect();
+    if missing.is_empty() {
+        return;
+    }
+
+    for language in missing {
+        warn!(
+            "{} is missing, showing {language:?} in English",
+            language.font()
+        );
+        locale.missing_fonts.push(language);
+    }
+    let language = settings.map_or(locale.language, |settings| settings.language);
+    locale.language = locale.shown_language(language);
+}
+
+fn localize_texts(locale: Res<Locale>, mut text_query: Query<(Ref<LocalizedText>, &mut Text)>) {
+    for (localized_text, mut text) in &mut text_query {
+        if !locale.is_changed() && !localized_text.is_added() {
+            continue;
+        }
+
+        for (index, string) in &localized_text.sections {
+            let Some(section) = text.sections.get_mut(*index) else {
+                continue;
+            };
+            let value = locale.text(*string);
+            if section.value != value {
+                section.value = value.to_string();
+            }
+            section.style.font = locale.font();
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    input::{AxisDirection, InputMap, InputMapPlugin, ResetInput},
+    locale::{Locale, LocalizedString, LocalizedText},
+    sfx::SfxEvent,
+    theme::{Theme, ThemeColor, Themed},
+};
+
+const MENU_BINDINGS: &str = "menu";
+
+const BUTTON_PADDING: Vec2 = Vec2::new(20.0, 10.0);
+const BUTTON_GAP: Val = Val::Px(10.0);
+const BUTTON_FONT_SIZE: f32 = 30.0;
+
+/// Moves between menu buttons and presses the selected one. `Pause` opens and closes the pause
+/// menu.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum MenuAction {
+    Previous,
+    Next,
+    Confirm,
+    Pause,
+}
+
+/// A button of one kind of menu, e.g. the result screen's.
+pub trait MenuButton: Component + Copy + Eq + Debug {
+    /// A key of the `Locale`'s catalogs, shown as is if they have none, or a text given in every
+    /// language.
+    fn label(self) -> LocalizedString;
+}
+
+/// The buttons of a menu on screen and the one selected. It only exists while the menu is shown.
+#[derive(Resource, Debug)]
+pub struct Menu<B: MenuButton> {
+    buttons: Vec<B>,
+    selected: usize,
+}
+
+impl<B: MenuButton> Menu<B> {
+    /// Selects `selected` if it is one of `buttons`, else the first.
+    pub fn new(buttons: Vec<B>, selected: Option<B>) -> Self {
+        let selected = buttons
+            .iter()
+            .position(|button| Some(*button) == selected)
+            .unwrap_or(0);
+        Self { buttons, selected }
+    }
+
+    pub fn buttons(&self) -> &[B] {
+        &self.buttons
+    }
+
+    pub fn selected(&self) -> Option<B> {
+        self.buttons.get(self.selected).copied()
+    }
+}
+
+/// Sent when a menu button is clicked, or confirmed while selected.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct MenuChosen<B: MenuButton>(pub B);
+
+#[derive(SystemSet, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct MenuSet;
+
+/// Drives every `Menu<B>` with the keyboard, a gamepad and the mouse. The press that chose a
+/// button is kept from reaching the game.
+pub struct MenuPlugin<B>(PhantomData<B>);
+
+impl<B> Default for MenuPlugin<B> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<B: MenuButton> Plugin for MenuPlugin<B> {
+    fn build(&self, app: &mut App) {
+        // Shared by every kind of menu
+        if !app.is_plugin_added::<InputMapPlugin<MenuAction>>() {
+            app.add_plugins(InputMapPlugin::<MenuAction>::default())
+                .insert_resource(menu_bindings());
+        }
+
+        app.add_event::<MenuChosen<B>>().add_systems(
+            Update,
+            (
+                select_menu_button::<B>,
+                choose_menu_button::<B>,
+                highlight_menu_buttons::<B>,
+            )
+                .chain()
+                .in_set(MenuSet)
+                .run_if(resource_exists::<Menu<B>>),
+        );
+    }
+}
+
+fn menu_bindings() -> InputMap<MenuAction> {
+    InputMap::new(MENU_BINDINGS)
+        .bind(MenuAction::Previous, KeyCode::ArrowLeft)
+        .bind(MenuAction::Previous, KeyCode::ArrowUp)
+        .bind(MenuAction::Previous, KeyCode::KeyA)
+        .bind(MenuAction::Previous, KeyCode::KeyW)
+        .bind(MenuAction::Next, KeyCode::ArrowRight)
+        .bind(MenuAction::Next, KeyCode::ArrowDown)
+        .bind(MenuAction::Next, KeyCode::KeyD)
+        .bind(MenuAction::Next, KeyCode::KeyS)
+        .bind(MenuAction::Confirm, KeyCode::Enter)
+        .bind(MenuAction::Confirm, KeyCode::Space)
+        .bind(MenuAction::Pause, KeyCode::Escape)
+        .bind(
+            MenuAction::Previous,
+            (GamepadAxisType::LeftStickX, AxisDirection::Negative),
+        )
+        .bind(
+            MenuAction::Previous,
+            (GamepadAxisType::LeftStickY, AxisDirection::Positive),
+        )
+        .bind(
+            MenuAction::Next,
+            (GamepadAxisType::LeftStickX, AxisDirection::Positive),
+        )
+        .bind(
+            MenuAction::Next,
+            (GamepadAxisType::LeftStickY, AxisDirection::Negative),
+        )
+        .bind(MenuAction::Previous, GamepadButtonType::DPadLeft)
+        .bind(MenuAction::Previous, GamepadButtonType::DPadUp)
+        .bind(MenuAction::Next, GamepadButtonType::DPadRight)
+        .bind(MenuAction::Next, GamepadButtonType::DPadDown)
+        .bind(MenuAction::Confirm, GamepadButtonType::South)
+        .bind(MenuAction::Pause, GamepadButtonType::Start)
+        .load()
+}
+
+/// Spawns a button per entry of `buttons`, laid out along `direction`, labelled in the language
+/// of `locale` and coloured by `theme`.
+pub fn spawn_menu_buttons<B: MenuButton>(
+    parent: &mut ChildBuilder,
+    buttons: &[B],
+    direction: FlexDirection,
+    locale: &Locale,
+    theme: &Theme,
+) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: direction,
+                align_items: AlignItems::Stretch,
+                row_gap: BUTTON_GAP,
+                column_gap: BUTTON_GAP,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for button in buttons {
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                padding: UiRect::axes(
+                                    Val::Px(BUTTON_PADDING.x),
+                                    Val::Px(BUTTON_PADDING.y),
+                                ),
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            },
+                            background_color: theme.button.into(),
+                            ..default()
+                        },
+                        *button,
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            TextBundle::from_section(
+                                locale.text(button.label()),
+                                locale.text_style(BUTTON_FONT_SIZE, theme.button_text),
+                            ),
+                            LocalizedText::new(button.label()),
+                            Themed(ThemeColor::ButtonText),
+                        ));
+                    });
+            }
+        });
+}
+
+fn select_menu_button<B: MenuButton>(
+    menu_input: Res<ButtonInput<MenuAction>>,
+    interaction_query: Query<(&Interaction, &B), Changed<Interaction>>,
+    mut menu: ResMut<Menu<B>>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    let button_count = menu.buttons.len();
+    if button_count == 0 {
+        return;
+    }
+    let previously_selected = menu.selected;
+
+    if menu_input.just_pressed(MenuAction::Previous) {
+        menu.selected = (menu.selected + button_count - 1) % button_count;
+    }
+    if menu_input.just_pressed(MenuAction::Next) {
+        menu.selected = (menu.selected + 1) % button_count;
+    }
+
+    // The mouse selects what it hovers
+    for (interaction, button) in &interaction_query {
+        if *interaction == Interaction::None {
+            continue;
+        }
+        if let Some(index) = menu.buttons.iter().position(|other| other == button) {
+            menu.selected = index;
+        }
+    }
+
+    if menu.selected != previously_selected {
+        sfx_events.send(SfxEvent::UiSelect);
+    }
+}
+
+fn choose_menu_button<B: MenuButton>(
+    menu_input: Res<ButtonInput<MenuAction>>,
+    interaction_query: Query<(&Interaction, &B), Changed<Interaction>>,
+    menu: Res<Menu<B>>,
+    mut chosen_events: EventWriter<MenuChosen<B>>,
+    mut sfx_events: EventWriter<SfxEvent>,
+    mut reset_input: ResetInput,
+) {
+    let clicked = interaction_query
+        .iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, button)| *button);
+    let confirmed = menu_input
+        .just_pressed(MenuAction::Confirm)
+        .then(|| menu.selected())
+        .flatten();
+
+    let Some(button) = clicked.or(confirmed) else {
+        return;
+    };
+
+    chosen_events.send(MenuChosen(button));
+    sfx_events.send(SfxEvent::UiConfirm);
+    reset_input.reset_all();
+}
+
+fn highlight_menu_buttons<B: MenuButton>(
+    menu: Res<Menu<B>>,
+    theme: Res<Theme>,
+    mut button_query: Query<(&B, &mut BackgroundColor)>,
+) {
+    if !menu.is_changed() && !theme.is_changed() {
+        return;
+    }
+
+    let selected = menu.selected();
+    for (button, mut background_color) in &mut button_query {
+        *background_color = if Some(*button) == selected {
+            theme.button_selected.into()
+        } else {
+            theme.button.into()
+        };
+    }
+}
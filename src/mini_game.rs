@@ -0,0 +1,160 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{
+    arcade::ArcadePlugin,
+    collision::CollisionPlane,
+    high_scores::ScoreOrder,
+    input::{Action, InputMap},
+    loading::{AssetManifest, GameModels},
+    music::{GameMusic, MusicPlayer},
+    play_area::PlayArea,
+    spatial_hash::SpatialHash,
+    theme::{GameColors, Theme},
+    ActiveGame, AppState, CurrentGame,
+};
+
+/// A text in each language the games are shown in.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct Localized {
+    pub en: &'static str,
+    pub ja: &'static str,
+}
+
+/// What a game tells the arcade, the menus and the tests about itself.
+#[derive(Clone, Copy, Debug)]
+pub struct MiniGameInfo {
+    /// Also keys its high scores, replays and key bindings.
+    pub id: &'static str,
+    pub name: Localized,
+    pub description: Localized,
+    pub controls: Localized,
+    /// The size of its `PlayArea`, and of its window when it runs on its own. Any other window
+    /// letterboxes it.
+    pub window_size: Vec2,
+    /// Its own colours, which the `Theme` keeps where the palette has none of its own.
+    pub colors: GameColors,
+    pub score_order: ScoreOrder,
+    /// What it loads before its title screen.
+    pub assets: AssetManifest,
+    /// Played while it is the `ActiveGame`.
+    pub music: GameMusic,
+}
+
+/// A game's plugin, which adds `HostedGamePlugin::<Self>` to be hosted by `MiniGamePlugin`.
+pub trait MiniGame: Plugin {
+    const INFO: MiniGameInfo;
+
+    /// The state the game's setup and teardown are scoped to.
+    fn active() -> ActiveGame {
+        ActiveGame(Some(Self::INFO.id))
+    }
+}
+
+/// Every hosted game, in the order their plugins were added.
+#[derive(Resource, Default, Debug)]
+pub struct MiniGames(Vec<MiniGameInfo>);
+
+impl MiniGames {
+    pub fn iter(&self) -> impl Iterator<Item = &MiniGameInfo> {
+        self.0.iter()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&MiniGameInfo> {
+        self.0.iter().find(|info| info.id == id)
+    }
+}
+
+/// Every system of one game, which only runs while that game is the `ActiveGame`.
+#[derive(SystemSet, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct GameSystems(pub &'static str);
+
+/// A game's own systems on becoming the `ActiveGame` and on stopping being it. `Setup` inserts
+/// the resources it keeps across runs, after `CurrentGame`, the `PlayArea` and the game's colours
+/// in the `Theme` are set, and `Teardown` removes them again.
+#[derive(SystemSet, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum MiniGameSet {
+    Setup,
+    Teardown,
+}
+
+/// Registers a game in `MiniGames` and gates its `GameSystems`. Without the arcade's hub the game
+/// is the only one and starts right away.
+pub struct HostedGamePlugin<G>(PhantomData<G>);
+
+impl<G> Default for HostedGamePlugin<G> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<G: MiniGame> Plugin for HostedGamePlugin<G> {
+    fn build(&self, app: &mut App) {
+        let id = G::INFO.id;
+        let game_systems = || GameSystems(id).run_if(in_state(G::active()));
+
+        app.init_resource::<MiniGames>();
+        app.world_mut().resource_mut::<MiniGames>().0.push(G::INFO);
+
+        app.configure_sets(PreUpdate, game_systems())
+            .configure_sets(Update, game_systems())
+            .configure_sets(PostUpdate, game_systems())
+            .configure_sets(FixedUpdate, game_systems());
+        for state in [
+            AppState::Loading,
+            AppState::MainMenu,
+            AppState::InGame,
+            AppState::Paused,
+            AppState::GameOver,
+            AppState::GameClear,
+        ] {
+            app.configure_sets(OnEnter(state), game_systems())
+                .configure_sets(OnExit(state), game_systems());
+        }
+
+        app.add_systems(
+            OnEnter(G::active()),
+            enter_game::<G>.before(MiniGameSet::Setup),
+        )
+        .add_systems(OnExit(G::active()), exit_game.after(MiniGameSet::Teardown));
+    }
+
+    fn finish(&self, app: &mut App) {
+        if !app.is_plugin_added::<ArcadePlugin>() {
+            app.insert_state(G::active());
+        }
+    }
+}
+
+// The window keeps its size, e.g. a browser's, the play area is letterboxed into it
+fn enter_game<G: MiniGame>(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut theme: ResMut<Theme>,
+    mut music_player: ResMut<MusicPlayer>,
+) {
+    commands.insert_resource(CurrentGame {
+        id: G::INFO.id,
+        score_order: G::INFO.score_order,
+    });
+    commands.insert_resource(PlayArea::new(G::INFO.window_size));
+    commands.insert_resource(GameModels::new(&G::INFO.assets, &asset_server));
+    music_player.set_game_music(G::INFO.music, &asset_server);
+    let game_theme = theme.with_game_colors(G::INFO.colors);
+    theme.set_if_neq(game_theme);
+}
+
+// Resources every game may replace, back to how the next game expects them
+fn exit_game(mut commands: Commands, mut theme: ResMut<Theme>) {
+    commands.remove_resource::<CurrentGame>();
+    commands.remove_resource::<PlayArea>();
+    commands.remove_resource::<GameModels>();
+    commands.remove_resource::<InputMap<Action>>();
+    commands.insert_resource(CollisionPlane::default());
+    commands.insert_resource(SpatialHash::default());
+    commands.insert_resource(Time::<Fixed>::default());
+    let palette_theme = theme.with_game_colors(&[]);
+    commands.insert_resource(ClearColor(palette_theme.background));
+    theme.set_if_neq(palette_theme);
+}
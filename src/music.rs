@@ -0,0 +1,233 @@
+use bevy::{
+    asset::LoadState,
+    audio::Volume,
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+use crate::{settings::Settings, sfx::Sfx, AppState};
+
+const CROSSFADE_SECS: f32 = 1.5;
+/// The music drops to this share of its volume while sound effects play.
+const DUCK_VOLUME: f32 = 0.4;
+const DUCK_SECS: f32 = 0.1;
+const UNDUCK_SECS: f32 = 0.5;
+
+/// The tracks of a game's title screen, its runs and its result screens, under `assets`. None
+/// ship with the games, a track only plays once its file is there.
+#[derive(Clone, Copy, Debug)]
+pub struct GameMusic {
+    pub title: &'static str,
+    pub play: &'static str,
+    pub result: &'static str,
+}
+
+impl GameMusic {
+    /// What every game plays unless it ships its own.
+    pub const DEFAULT: GameMusic = GameMusic {
+        title: "music/title.ogg",
+        play: "music/play.ogg",
+        result: "music/result.ogg",
+    };
+
+    pub fn paths(&self) -> [&'static str; 3] {
+        [self.title, self.play, self.result]
+    }
+}
+
+/// The looping track of each `AppState`, and of the arcade's hub. States without a track keep
+/// whatever is playing, so `Paused` carries on with the run's music and one track can serve both
+/// result states without starting over. Tracks whose file is missing are left out.
+#[derive(Resource, Debug)]
+pub struct MusicPlayer {
+    tracks: HashMap<AppState, Handle<AudioSource>>,
+    hub_track: Option<Handle<AudioSource>>,
+    playing: Option<Handle<AudioSource>>,
+    /// Found missing from `assets` at startup, so never loaded.
+    missing: HashSet<&'static str>,
+    /// Browsers block audio until the first user gesture, music waits for it instead of being
+    /// started silently.
+    unlocked: bool,
+    duck: f32,
+}
+
+impl Default for MusicPlayer {
+    fn default() -> Self {
+        Self {
+            tracks: HashMap::default(),
+            hub_track: None,
+            playing: None,
+            missing: HashSet::default(),
+            unlocked: !cfg!(target_arch = "wasm32"),
+            duck: 1.0,
+        }
+    }
+}
+
+impl MusicPlayer {
+    pub fn set_track(&mut self, state: AppState, track: Handle<AudioSource>) {
+        self.tracks.insert(state, track);
+    }
+
+    /// The tracks of a game, for each of its states.
+    pub fn set_game_music(&mut self, music: GameMusic, asset_server: &AssetServer) {
+        for (state, path) in [
+            (AppState::MainMenu, music.title),
+            (AppState::InGame, music.play),
+            (AppState::GameOver, music.result),
+            (AppState::GameClear, music.result),
+        ] {
+            match self.load(path, asset_server) {
+                Some(track) => self.set_track(state, track),
+                None => {
+                    self.tracks.remove(&state);
+                }
+            }
+        }
+    }
+
+    /// Played while the arcade's hub is shown, where there is no `AppState`.
+    pub fn set_hub_track(&mut self, path: &'static str, asset_server: &AssetServer) {
+        self.hub_track = self.load(path, asset_server);
+    }
+
+    /// Keeps the track at `path` from being loaded, and from logging that it failed to.
+    pub fn mark_missing(&mut self, path: &'static str) {
+        self.missing.insert(path);
+    }
+
+    fn load(&self, path: &'static str, asset_server: &AssetServer) -> Option<Handle<AudioSource>> {
+        (!self.missing.contains(path)).then(|| asset_server.load(path))
+    }
+
+    pub fn playing(&self) -> Option<&Handle<AudioSource>> {
+        self.playing.as_ref()
+    }
+}
+
+/// A playing track, fading in until it is replaced and then fading out.
+#[derive(Component, Debug)]
+pub struct Music {
+    level: f32,
+    fading_out: bool,
+}
+
+impl Music {
+    /// How far the fade has come, from 0.0 (silent) to 1.0.
+    pub fn level(&self) -> f32 {
+        self.level
+    }
+
+    pub fn fading_out(&self) -> bool {
+        self.fading_out
+    }
+}
+
+/// Plays the `MusicPlayer`'s tracks at the music volume of the `Settings`, crossfading on every
+/// state change and ducking under sound effects. Fades go by real time, so they finish while the
+/// game is paused.
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MusicPlayer>()
+            .add_systems(PostUpdate, (unlock_music, change_track, fade_music).chain());
+    }
+}
+
+// The page's restart-audio-context.js resumes the audio context on the same gestures
+fn unlock_music(
+    mut music_player: ResMut<MusicPlayer>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+) {
+    if music_player.unlocked {
+        return;
+    }
+
+    if keyboard_input.get_just_pressed().next().is_some()
+        || mouse_input.get_just_pressed().next().is_some()
+        || touches.iter_just_released().next().is_some()
+    {
+        music_player.unlocked = true;
+    }
+}
+
+fn change_track(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut music_player: ResMut<MusicPlayer>,
+    app_state: Option<Res<State<AppState>>>,
+    mut music_query: Query<&mut Music>,
+) {
+    if !music_player.unlocked {
+        return;
+    }
+    // The arcade's hub has no `AppState`
+    let track = match app_state {
+        Some(app_state) => music_player.tracks.get(app_state.get()),
+        None => music_player.hub_track.as_ref(),
+    };
+    let Some(track) = track.cloned() else {
+        return;
+    };
+    // Also on the web, where missing files only show up once they fail to load
+    if music_player.playing.as_ref() == Some(&track)
+        || matches!(asset_server.load_state(&track), LoadState::Failed(_))
+    {
+        return;
+    }
+
+    for mut music in &mut music_query {
+        music.fading_out = true;
+    }
+    commands.spawn((
+        AudioBundle {
+            source: track.clone(),
+            // Silent until the fade turns it up
+            settings: PlaybackSettings::LOOP.with_volume(Volume::ZERO),
+        },
+        Music {
+            level: 0.0,
+            fading_out: false,
+        },
+    ));
+    music_player.playing = Some(track);
+}
+
+fn fade_music(
+    mut commands: Commands,
+    mut music_player: ResMut<MusicPlayer>,
+    settings: Res<Settings>,
+    time: Res<Time<Real>>,
+    sfx_query: Query<(), With<Sfx>>,
+    mut music_query: Query<(Entity, &mut Music, Option<&AudioSink>)>,
+) {
+    let delta = time.delta_seconds();
+
+    let duck = &mut music_player.duck;
+    if sfx_query.is_empty() {
+        *duck = (*duck + delta * (1.0 - DUCK_VOLUME) / UNDUCK_SECS).min(1.0);
+    } else {
+        *duck = (*duck - delta * (1.0 - DUCK_VOLUME) / DUCK_SECS).max(DUCK_VOLUME);
+    }
+    let volume = music_player.duck * settings.music_volume * settings.master_volume;
+
+    for (entity, mut music, sink) in &mut music_query {
+        if music.fading_out {
+            music.level -= delta / CROSSFADE_SECS;
+            if music.level <= 0.0 {
+                commands.entity(entity).despawn();
+                continue;
+            }
+        } else {
+            music.level = (music.level + delta / CROSSFADE_SECS).min(1.0);
+        }
+
+        // The sink only shows up once the track has loaded and started
+        if let Some(sink) = sink {
+            sink.set_volume(music.level * volume);
+        }
+    }
+}
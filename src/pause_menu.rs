@@ -0,0 +1,247 @@
+use bevy::{input::gamepad::GamepadConnectionEvent, prelude::*, window::WindowFocused};
+
+use crate::{
+    despawn_run,
+    input::ResetInput,
+    locale::{Locale, LocalizedString, LocalizedText},
+    menu::{spawn_menu_buttons, Menu, MenuAction, MenuButton, MenuChosen, MenuPlugin, MenuSet},
+    reset_rng,
+    settings::SettingsScreen,
+    theme::Theme,
+    AppState, Restarting,
+};
+
+const PAUSE_PADDING: Val = Val::Px(40.0);
+const PAUSE_GAP: Val = Val::Px(20.0);
+const PAUSED_FONT_SIZE: f32 = 60.0;
+
+/// What is shown while `Paused`, the pause menu or the `SettingsScreen` it leads to.
+#[derive(SubStates, Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+#[source(AppState = AppState::Paused)]
+pub enum PauseScreen {
+    #[default]
+    Menu,
+    Settings,
+}
+
+#[derive(Component, Clone, Copy, Eq, PartialEq, Debug)]
+pub enum PauseButton {
+    Resume,
+    Restart,
+    Settings,
+    Quit,
+}
+
+impl MenuButton for PauseButton {
+    fn label(self) -> LocalizedString {
+        match self {
+            PauseButton::Resume => "resume",
+            PauseButton::Restart => "restart",
+            PauseButton::Settings => "settings",
+            PauseButton::Quit => "quit_to_title",
+        }
+        .into()
+    }
+}
+
+#[derive(Component)]
+pub struct PausePanel;
+
+/// Pauses a run on Esc or Start, when a gamepad is plugged in or out, and when the window loses
+/// focus or the browser tab is hidden. `Time<Virtual>` stands still while the pause menu is
+/// shown, so neither `Update` timers nor `FixedUpdate` ticks advance.
+pub struct PauseMenuPlugin;
+
+impl Plugin for PauseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        // Also without a `WindowPlugin`, e.g. in headless tests
+        app.add_event::<WindowFocused>()
+            .add_sub_state::<PauseScreen>()
+            .add_plugins(MenuPlugin::<PauseButton>::default())
+            .add_systems(Update, pause_game.run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::Paused), (pause_time, clear_restarting))
+            .add_systems(OnEnter(PauseScreen::Menu), spawn_pause_menu)
+            .add_systems(
+                Update,
+                leave_pause_menu
+                    .after(MenuSet)
+                    .run_if(in_state(PauseScreen::Menu)),
+            )
+            // Runs before leaving `Paused`, so the run below never despawns the panel twice
+            .add_systems(
+                OnExit(PauseScreen::Menu),
+                (despawn_pause_panel, remove_pause_menu),
+            )
+            .add_systems(OnEnter(PauseScreen::Settings), open_settings)
+            .add_systems(
+                OnExit(SettingsScreen::Open),
+                return_from_settings.run_if(in_state(PauseScreen::Settings)),
+            )
+            .add_systems(OnExit(AppState::Paused), unpause_time)
+            // Restarting or quitting ends the run like the result screen does
+            .add_systems(
+                OnExit(AppState::Paused),
+                (despawn_run, reset_rng).run_if(ending_run),
+            );
+    }
+}
+
+fn pause_game(
+    menu_input: Res<ButtonInput<MenuAction>>,
+    mut connection_events: EventReader<GamepadConnectionEvent>,
+    mut focus_events: EventReader<WindowFocused>,
+    mut app_state: ResMut<NextState<AppState>>,
+    mut reset_input: ResetInput,
+) {
+    let pressed = menu_input.just_pressed(MenuAction::Pause);
+    // Plugging a controller in or out mid-run pauses instead of leaving the player without control
+    let gamepad_changed = connection_events.read().count() > 0;
+    let focus_lost = focus_events.read().any(|event| !event.focused);
+
+    if pressed || gamepad_changed || focus_lost || tab_hidden() {
+        app_state.set(AppState::Paused);
+        reset_input.reset_all();
+    }
+}
+
+// A hidden tab keeps the window focused on some browsers
+#[cfg(target_arch = "wasm32")]
+fn tab_hidden() -> bool {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .is_some_and(|document| document.hidden())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn tab_hidden() -> bool {
+    false
+}
+
+fn pause_time(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
+
+fn unpause_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
+
+fn clear_restarting(mut restarting: ResMut<Restarting>) {
+    restarting.0 = false;
+}
+
+fn spawn_pause_menu(mut commands: Commands, locale: Res<Locale>, theme: Res<Theme>) {
+    let menu = Menu::new(
+        vec![
+            PauseButton::Resume,
+            PauseButton::Restart,
+            PauseButton::Settings,
+            PauseButton::Quit,
+        ],
+        None,
+    );
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: theme.backdrop.into(),
+                ..default()
+            },
+            PausePanel,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        padding: UiRect::all(PAUSE_PADDING),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: PAUSE_GAP,
+                        ..default()
+                    },
+                    background_color: theme.panel.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            locale.get("paused"),
+                            locale.text_style(PAUSED_FONT_SIZE, theme.panel_text),
+                        ),
+                        LocalizedText::new("paused"),
+                    ));
+
+                    spawn_menu_buttons(
+                        parent,
+                        menu.buttons(),
+                        FlexDirection::Column,
+                        &locale,
+                        &theme,
+                    );
+                });
+        });
+
+    commands.insert_resource(menu);
+}
+
+fn leave_pause_menu(
+    menu_input: Res<ButtonInput<MenuAction>>,
+    mut chosen_events: EventReader<MenuChosen<PauseButton>>,
+    mut restarting: ResMut<Restarting>,
+    mut app_state: ResMut<NextState<AppState>>,
+    mut pause_screen: ResMut<NextState<PauseScreen>>,
+    mut reset_input: ResetInput,
+) {
+    // Pressing pause again resumes, like the Resume button
+    if menu_input.just_pressed(MenuAction::Pause) {
+        app_state.set(AppState::InGame);
+        reset_input.reset_all();
+    }
+
+    for MenuChosen(button) in chosen_events.read() {
+        match button {
+            PauseButton::Resume => app_state.set(AppState::InGame),
+            PauseButton::Restart => {
+                restarting.0 = true;
+                app_state.set(AppState::InGame);
+            }
+            PauseButton::Settings => pause_screen.set(PauseScreen::Settings),
+            PauseButton::Quit => app_state.set(AppState::MainMenu),
+        }
+    }
+}
+
+fn open_settings(mut settings_screen: ResMut<NextState<SettingsScreen>>) {
+    settings_screen.set(SettingsScreen::Open);
+}
+
+fn return_from_settings(mut pause_screen: ResMut<NextState<PauseScreen>>) {
+    pause_screen.set(PauseScreen::Menu);
+}
+
+fn remove_pause_menu(mut commands: Commands) {
+    commands.remove_resource::<Menu<PauseButton>>();
+}
+
+fn despawn_pause_panel(mut commands: Commands, panel_query: Query<Entity, With<PausePanel>>) {
+    for panel_entity in &panel_query {
+        commands.entity(panel_entity).despawn_recursive();
+    }
+}
+
+// Anything but going back to the same run, which the pause left alone
+fn ending_run(
+    mut transitions: EventReader<StateTransitionEvent<AppState>>,
+    restarting: Res<Restarting>,
+) -> bool {
+    restarting.0
+        || transitions
+            .read()
+            .last()
+            .is_some_and(|transition| transition.entered != Some(AppState::InGame))
+}
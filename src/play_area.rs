@@ -0,0 +1,171 @@
+use bevy::{
+    prelude::*,
+    render::camera::{CameraUpdateSystem, ScalingMode, Viewport},
+    window::PrimaryWindow,
+};
+
+/// The fixed logical area a game is laid out in, centered on the origin. `PlayAreaCamera`s show
+/// exactly this area, scaled to fit the window and letterboxed, so positions and bounds never
+/// depend on the window's size.
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+pub struct PlayArea {
+    size: Vec2,
+    /// Logical window pixels per world unit.
+    scale: f32,
+    /// Top left corner of the play area in window coordinates.
+    origin: Vec2,
+    physical_position: UVec2,
+    physical_size: UVec2,
+}
+
+impl PlayArea {
+    pub fn new(size: Vec2) -> Self {
+        Self {
+            size,
+            scale: 1.0,
+            origin: Vec2::ZERO,
+            physical_position: UVec2::ZERO,
+            physical_size: size.as_uvec2(),
+        }
+    }
+
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    pub fn half_size(&self) -> Vec2 {
+        self.size / 2.0
+    }
+
+    pub fn left(&self) -> f32 {
+        -self.size.x / 2.0
+    }
+
+    pub fn right(&self) -> f32 {
+        self.size.x / 2.0
+    }
+
+    pub fn bottom(&self) -> f32 {
+        -self.size.y / 2.0
+    }
+
+    pub fn top(&self) -> f32 {
+        self.size.y / 2.0
+    }
+
+    pub fn top_left(&self) -> Vec2 {
+        Vec2::new(self.left(), self.top())
+    }
+
+    /// Whether `position` is inside, or at most `margin` outside on either axis.
+    pub fn contains(&self, position: Vec2, margin: Vec2) -> bool {
+        let half_size = self.half_size() + margin;
+        position.x.abs() <= half_size.x && position.y.abs() <= half_size.y
+    }
+
+    /// How much larger than its logical size the play area is shown.
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// A position in window coordinates (origin top left, y down) in world units, also outside
+    /// the play area, e.g. on the letterbox.
+    pub fn window_to_world(&self, position: Vec2) -> Vec2 {
+        let position = (position - self.origin) / self.scale;
+        Vec2::new(
+            position.x - self.size.x / 2.0,
+            self.size.y / 2.0 - position.y,
+        )
+    }
+
+    /// The window coordinates of a position in world units, the inverse of `window_to_world`.
+    pub fn world_to_window(&self, position: Vec2) -> Vec2 {
+        let position = Vec2::new(
+            position.x + self.size.x / 2.0,
+            self.size.y / 2.0 - position.y,
+        );
+        position * self.scale + self.origin
+    }
+
+    /// A movement in window coordinates in world units, with y pointing up.
+    pub fn window_to_world_delta(&self, delta: Vec2) -> Vec2 {
+        Vec2::new(delta.x, -delta.y) / self.scale
+    }
+
+    fn fit(self, window: &Window) -> Self {
+        let window_size = window.physical_size().as_vec2();
+        let physical_scale = (window_size / self.size).min_element();
+        let physical_size = (self.size * physical_scale).round();
+        let physical_position = ((window_size - physical_size) / 2.0).floor();
+
+        Self {
+            size: self.size,
+            scale: physical_scale / window.scale_factor(),
+            origin: physical_position / window.scale_factor(),
+            physical_position: physical_position.as_uvec2(),
+            physical_size: physical_size.as_uvec2(),
+        }
+    }
+
+    fn viewport(&self) -> Viewport {
+        Viewport {
+            physical_position: self.physical_position,
+            physical_size: self.physical_size,
+            ..default()
+        }
+    }
+}
+
+/// A camera showing the `PlayArea`. 2D cameras are given a projection of exactly its size.
+#[derive(Component)]
+pub struct PlayAreaCamera;
+
+/// Keeps the `PlayArea` fitted to the primary window and its cameras' viewports on it. The
+/// letterbox around it is left in the `ClearColor`.
+pub struct PlayAreaPlugin;
+
+impl Plugin for PlayAreaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            fit_play_area
+                .before(CameraUpdateSystem)
+                .run_if(resource_exists::<PlayArea>),
+        );
+    }
+}
+
+fn fit_play_area(
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut play_area: ResMut<PlayArea>,
+    mut camera_query: Query<(
+        Ref<PlayAreaCamera>,
+        &mut Camera,
+        Option<&mut OrthographicProjection>,
+    )>,
+) {
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    // Minimized
+    if window.physical_width() == 0 || window.physical_height() == 0 {
+        return;
+    }
+
+    let fitted = play_area.fit(window);
+    play_area.set_if_neq(fitted);
+
+    for (play_area_camera, mut camera, projection) in &mut camera_query {
+        if !play_area.is_changed() && !play_area_camera.is_added() {
+            continue;
+        }
+
+        camera.viewport = Some(play_area.viewport());
+        if let Some(mut projection) = projection {
+            projection.scaling_mode = ScalingMode::Fixed {
+                width: play_area.size.x,
+                height: play_area.size.y,
+            };
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use bevy::{input::InputSystem, prelude::*, window::PrimaryWindow};
+use serde::{Deserialize, Serialize};
+
+use crate::play_area::PlayArea;
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum PointerId {
+    Mouse,
+    Touch(u64),
+}
+
+/// A held-down mouse button or finger, in window coordinates (origin top left, y down).
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Pointer {
+    pub id: PointerId,
+    pub position: Vec2,
+    /// Movement since the previous frame, zero on the frame it was pressed.
+    pub delta: Vec2,
+    pub just_pressed: bool,
+}
+
+impl Pointer {
+    /// Position in world units as seen by the `PlayAreaCamera`.
+    pub fn world_position(&self, play_area: &PlayArea) -> Vec2 {
+        play_area.window_to_world(self.position)
+    }
+
+    /// Drag distance in world units, with y pointing up.
+    pub fn world_delta(&self, play_area: &PlayArea) -> Vec2 {
+        play_area.window_to_world_delta(self.delta)
+    }
+}
+
+/// Every pointer held down this frame. The left mouse button and touches are treated alike, so
+/// games written against `Pointers` work the same with a mouse and on a phone.
+#[derive(Resource, Default, Debug)]
+pub struct Pointers {
+    pressed: Vec<Pointer>,
+    last_cursor_position: Option<Vec2>,
+}
+
+impl Pointers {
+    pub fn iter(&self) -> impl Iterator<Item = &Pointer> {
+        self.pressed.iter()
+    }
+
+    pub fn iter_just_pressed(&self) -> impl Iterator<Item = &Pointer> {
+        self.pressed.iter().filter(|pointer| pointer.just_pressed)
+    }
+
+    pub fn any_pressed(&self) -> bool {
+        !self.pressed.is_empty()
+    }
+
+    pub fn any_just_pressed(&self) -> bool {
+        self.iter_just_pressed().next().is_some()
+    }
+
+    /// Summed drag of every pointer in world units, e.g. to move a player with a finger.
+    pub fn world_drag(&self, play_area: &PlayArea) -> Vec2 {
+        self.pressed
+            .iter()
+            .map(|pointer| pointer.world_delta(play_area))
+            .sum()
+    }
+
+    /// Replaces the live pointers, e.g. with recorded ones.
+    pub fn set_pressed(&mut self, pressed: Vec<Pointer>) {
+        self.pressed = pressed;
+    }
+
+    /// Forgets the presses of this frame, like `ButtonInput::reset_all`.
+    pub fn reset_all(&mut self) {
+        self.pressed.clear();
+    }
+}
+
+#[derive(SystemSet, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct PointerSystem;
+
+pub struct PointerPlugin;
+
+impl Plugin for PointerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Pointers>().add_systems(
+            PreUpdate,
+            update_pointers.in_set(PointerSystem).after(InputSystem),
+        );
+    }
+}
+
+pub fn update_pointers(
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    mut pointers: ResMut<Pointers>,
+) {
+    let cursor_position = window_query
+        .get_single()
+        .ok()
+        .and_then(Window::cursor_position);
+    let last_cursor_position = pointers.last_cursor_position;
+
+    pointers.pressed.clear();
+
+    if let Some(position) = cursor_position.filter(|_| mouse_input.pressed(MouseButton::Left)) {
+        let just_pressed = mouse_input.just_pressed(MouseButton::Left);
+        let delta = match (just_pressed, last_cursor_position) {
+            (false, Some(last_position)) => position - last_position,
+            _ => Vec2::ZERO,
+        };
+
+        pointers.pressed.push(Pointer {
+            id: PointerId::Mouse,
+            position,
+            delta,
+            just_pressed,
+        });
+    }
+
+    for touch in touches.iter() {
+        pointers.pressed.push(Pointer {
+            id: PointerId::Touch(touch.id()),
+            position: touch.position(),
+            delta: touch.delta(),
+            just_pressed: touches.just_pressed(touch.id()),
+        });
+    }
+
+    pointers.last_cursor_position = cursor_position;
+}
@@ -0,0 +1,356 @@
+use std::time::Duration;
+
+use bevy::{
+    app::RunFixedMainLoop,
+    prelude::*,
+    time::{run_fixed_main_schedule, TimeUpdateStrategy},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    input::{queue_fixed_actions, Action},
+    launch,
+    pointer::{Pointer, Pointers},
+    resuming,
+    rng::GameRng,
+    storage, AppState, CurrentGame, PressAnyKey,
+};
+
+const REPLAY_OPTION: &str = "replay";
+
+fn one() -> u32 {
+    1
+}
+
+fn is_one(repeat: &u32) -> bool {
+    *repeat == 1
+}
+
+/// The action input and frame time of `repeat` identical frames in a row.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ReplayTick {
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub repeat: u32,
+    pub delta_nanos: u64,
+    /// `InGame` was entered this frame, which restarts the fixed timestep.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub entered: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pressed: Vec<Action>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub axes: Vec<(Action, f32)>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pointers: Vec<Pointer>,
+}
+
+impl ReplayTick {
+    pub fn delta(&self) -> Duration {
+        Duration::from_nanos(self.delta_nanos)
+    }
+
+    // `entered` only ever applies to the first frame of a tick
+    fn same_input(&self, other: &ReplayTick) -> bool {
+        ReplayTick {
+            repeat: other.repeat,
+            entered: other.entered,
+            ..self.clone()
+        } == *other
+    }
+}
+
+/// One run of a game, from entering `InGame` to its result screen.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Replay {
+    pub game: String,
+    pub seed: u64,
+    pub ticks: Vec<ReplayTick>,
+}
+
+impl Replay {
+    pub fn new(game: &str, seed: u64) -> Self {
+        Self {
+            game: game.to_string(),
+            seed,
+            ticks: Vec::new(),
+        }
+    }
+
+    /// Appends one frame, merged into the last tick when nothing changed.
+    pub fn push(&mut self, tick: ReplayTick) {
+        match self.ticks.last_mut() {
+            Some(last) if !tick.entered && last.same_input(&tick) => last.repeat += tick.repeat,
+            _ => self.ticks.push(tick),
+        }
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.ticks.iter().map(|tick| tick.repeat as usize).sum()
+    }
+
+    /// The last run of `game`, saved next to the high scores.
+    pub fn file_name(game: &str) -> String {
+        format!("replays/{game}.json")
+    }
+
+    /// Reads a replay from a file path, or from a name in the game's own storage.
+    pub fn load(name: &str) -> Option<Self> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let contents = std::fs::read_to_string(name)
+            .ok()
+            .or_else(|| storage::load(name));
+        #[cfg(target_arch = "wasm32")]
+        let contents = storage::load(name);
+
+        let Some(contents) = contents else {
+            warn!("no replay found at {name}");
+            return None;
+        };
+        match serde_json::from_str(&contents) {
+            Ok(replay) => Some(replay),
+            Err(error) => {
+                warn!("ignoring unreadable replay {name}: {error}");
+                None
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string(self) {
+            Ok(contents) => storage::save(&Self::file_name(&self.game), &contents),
+            Err(error) => warn!("failed to serialize replay of {}: {error}", self.game),
+        }
+    }
+}
+
+/// Records every run and saves it when the result screen shows.
+#[derive(Resource, Default)]
+pub struct ReplayRecorder {
+    replay: Option<Replay>,
+    entered: bool,
+    /// The most recently finished run, e.g. to check it in tests.
+    pub last: Option<Replay>,
+}
+
+impl ReplayRecorder {
+    /// Whether the run in progress is played live, not fed from a `ReplayPlayer`.
+    pub fn is_recording(&self) -> bool {
+        self.replay.is_some()
+    }
+}
+
+/// Feeds a replay into the game in place of the live input.
+#[derive(Resource)]
+pub struct ReplayPlayer {
+    replay: Replay,
+    tick: usize,
+    repeat: u32,
+    pressed: Vec<Action>,
+    live_time_strategy: Option<TimeUpdateStrategy>,
+}
+
+impl ReplayPlayer {
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            replay,
+            tick: 0,
+            repeat: 0,
+            pressed: Vec::new(),
+            live_time_strategy: None,
+        }
+    }
+
+    fn current(&self) -> Option<&ReplayTick> {
+        self.replay.ticks.get(self.tick)
+    }
+
+    fn advance(&mut self) {
+        self.repeat += 1;
+        if self
+            .current()
+            .is_some_and(|tick| self.repeat >= tick.repeat)
+        {
+            self.tick += 1;
+            self.repeat = 0;
+        }
+    }
+}
+
+/// Loads the replay named by `--replay` (see `launch::option`) and plays it instead of the
+/// keyboard. Otherwise every run is recorded to `Replay::file_name`.
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        if let Some(replay) = launch::option(REPLAY_OPTION).and_then(|name| Replay::load(&name)) {
+            // Startup spawners draw from the recorded seed too
+            app.insert_resource(GameRng::new(replay.seed))
+                .insert_resource(ReplayPlayer::new(replay));
+        }
+
+        app.init_resource::<ReplayRecorder>()
+            .add_systems(Update, start_replay.run_if(in_state(AppState::MainMenu)))
+            .add_systems(
+                OnEnter(AppState::InGame),
+                start_recording.run_if(not(resuming)),
+            )
+            .add_systems(OnEnter(AppState::InGame), mark_entered)
+            .add_systems(OnEnter(AppState::GameOver), finish_recording)
+            .add_systems(OnEnter(AppState::GameClear), finish_recording)
+            .add_systems(
+                RunFixedMainLoop,
+                (play_tick, record_tick)
+                    .chain()
+                    .before(queue_fixed_actions::<Action>)
+                    .before(run_fixed_main_schedule)
+                    .run_if(in_state(AppState::InGame)),
+            );
+    }
+}
+
+fn start_replay(
+    mut commands: Commands,
+    player: Option<ResMut<ReplayPlayer>>,
+    pressanykey_query: Query<Entity, With<PressAnyKey>>,
+    mut time_strategy: ResMut<TimeUpdateStrategy>,
+    mut app_state: ResMut<NextState<AppState>>,
+) {
+    let Some(mut player) = player else {
+        return;
+    };
+    let Some(first_delta) = player.current().map(ReplayTick::delta) else {
+        return;
+    };
+
+    for pressanykey_entity in &pressanykey_query {
+        commands.entity(pressanykey_entity).despawn_recursive();
+    }
+
+    // Frames run exactly as long as they did when recorded
+    let live_time_strategy = std::mem::replace(
+        &mut *time_strategy,
+        TimeUpdateStrategy::ManualDuration(first_delta),
+    );
+    player.live_time_strategy.get_or_insert(live_time_strategy);
+    app_state.set(AppState::InGame);
+}
+
+fn start_recording(
+    mut recorder: ResMut<ReplayRecorder>,
+    player: Option<Res<ReplayPlayer>>,
+    current_game: Option<Res<CurrentGame>>,
+    rng: Res<GameRng>,
+) {
+    recorder.replay = match (player, current_game) {
+        (None, Some(current_game)) => Some(Replay::new(current_game.id, rng.seed())),
+        _ => None,
+    };
+}
+
+fn mark_entered(mut recorder: ResMut<ReplayRecorder>) {
+    recorder.entered = true;
+}
+
+fn finish_recording(mut recorder: ResMut<ReplayRecorder>) {
+    let Some(replay) = recorder.replay.take() else {
+        return;
+    };
+
+    replay.save();
+    recorder.last = Some(replay);
+}
+
+fn play_tick(
+    mut commands: Commands,
+    player: Option<ResMut<ReplayPlayer>>,
+    mut actions: ResMut<ButtonInput<Action>>,
+    mut action_axes: ResMut<Axis<Action>>,
+    mut pointers: ResMut<Pointers>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut time_strategy: ResMut<TimeUpdateStrategy>,
+) {
+    let Some(mut player) = player else {
+        return;
+    };
+    let Some(tick) = player.current().cloned() else {
+        return;
+    };
+
+    // Rebuild last frame's state first, so `just_pressed` matches the recording
+    actions.reset_all();
+    for action in &player.pressed {
+        actions.press(*action);
+    }
+    actions.clear();
+    for action in &player.pressed {
+        if !tick.pressed.contains(action) {
+            actions.release(*action);
+        }
+    }
+    for action in &tick.pressed {
+        actions.press(*action);
+    }
+
+    let devices: Vec<Action> = action_axes.devices().copied().collect();
+    for action in devices {
+        action_axes.set(action, 0.0);
+    }
+    for (action, value) in &tick.axes {
+        action_axes.set(*action, *value);
+    }
+    pointers.set_pressed(tick.pointers.clone());
+
+    if player.repeat == 0 && tick.entered {
+        let overstep = fixed_time.overstep();
+        fixed_time.discard_overstep(overstep);
+    }
+
+    player.pressed = tick.pressed;
+    player.advance();
+
+    match player.current().map(ReplayTick::delta) {
+        Some(next_delta) => *time_strategy = TimeUpdateStrategy::ManualDuration(next_delta),
+        None => {
+            info!("replay finished, back to live input");
+            *time_strategy = player.live_time_strategy.take().unwrap_or_default();
+            commands.remove_resource::<ReplayPlayer>();
+        }
+    }
+}
+
+fn record_tick(
+    mut recorder: ResMut<ReplayRecorder>,
+    actions: Res<ButtonInput<Action>>,
+    action_axes: Res<Axis<Action>>,
+    pointers: Res<Pointers>,
+    real_time: Res<Time<Real>>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+) {
+    let entered = std::mem::take(&mut recorder.entered);
+    let Some(replay) = recorder.replay.as_mut() else {
+        return;
+    };
+
+    // Whatever piled up before the run (or the pause) must not leak into it
+    if entered {
+        let overstep = fixed_time.overstep();
+        fixed_time.discard_overstep(overstep);
+    }
+
+    let mut pressed: Vec<Action> = actions.get_pressed().copied().collect();
+    pressed.sort_by_key(|action| *action as u8);
+    let mut axes: Vec<(Action, f32)> = action_axes
+        .devices()
+        .filter_map(|action| Some((*action, action_axes.get(*action)?)))
+        .filter(|(_, value)| *value != 0.0)
+        .collect();
+    axes.sort_by_key(|(action, _)| *action as u8);
+
+    replay.push(ReplayTick {
+        repeat: 1,
+        delta_nanos: real_time.delta().as_nanos() as u64,
+        entered,
+        pressed,
+        axes,
+        pointers: pointers.iter().copied().collect(),
+    });
+}
@@ -0,0 +1,264 @@
+use bevy::prelude::*;
+
+use crate::{
+    despawn_run,
+    high_scores::{spawn_high_score_table, Stat},
+    locale::{Locale, LocalizedString},
+    menu::{spawn_menu_buttons, Menu, MenuButton, MenuChosen, MenuPlugin, MenuSet},
+    sfx::SfxEvent,
+    theme::{Theme, ThemeColor},
+    AppState,
+};
+
+const RESULT_PADDING: Val = Val::Px(40.0);
+const RESULT_GAP: Val = Val::Px(10.0);
+const RESULT_FONT_SIZE: f32 = 50.0;
+const RESULT_FONT_SIZE_SMALL: f32 = 30.0;
+
+/// How a run ended, which decides the result state it switches to.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Outcome {
+    GameOver,
+    GameClear,
+}
+
+impl Outcome {
+    fn state(self) -> AppState {
+        match self {
+            Outcome::GameOver => AppState::GameOver,
+            Outcome::GameClear => AppState::GameClear,
+        }
+    }
+
+    fn heading(self) -> (&'static str, ThemeColor) {
+        match self {
+            Outcome::GameOver => ("game_over", ThemeColor::Failed),
+            Outcome::GameClear => ("game_clear", ThemeColor::Cleared),
+        }
+    }
+}
+
+/// Sent by a game when a run ends. A `score` is submitted to the high scores together with the
+/// `stats`, and both are shown on the result screen.
+#[derive(Event, Clone, Debug)]
+pub struct GameFinished {
+    pub outcome: Outcome,
+    pub score: Option<f32>,
+    pub stats: Vec<(Stat, String)>,
+    /// Offers the Next Stage button, for games made of stages.
+    pub next_stage: bool,
+}
+
+impl GameFinished {
+    pub fn new(outcome: Outcome) -> Self {
+        Self {
+            outcome,
+            score: None,
+            stats: Vec::new(),
+            next_stage: false,
+        }
+    }
+
+    pub fn with_score(mut self, score: f32) -> Self {
+        self.score = Some(score);
+        self
+    }
+
+    pub fn with_stat(mut self, stat: Stat, value: impl ToString) -> Self {
+        self.stats.push((stat, value.to_string()));
+        self
+    }
+
+    pub fn with_next_stage(mut self) -> Self {
+        self.next_stage = true;
+        self
+    }
+}
+
+#[derive(Component, Clone, Copy, Eq, PartialEq, Debug)]
+pub enum ResultButton {
+    Retry,
+    NextStage,
+    Title,
+}
+
+impl MenuButton for ResultButton {
+    fn label(self) -> LocalizedString {
+        match self {
+            ResultButton::Retry => "retry",
+            ResultButton::NextStage => "next_stage",
+            ResultButton::Title => "title",
+        }
+        .into()
+    }
+}
+
+/// The run shown on the result screen.
+#[derive(Resource, Default, Debug)]
+struct ResultScreen {
+    finished: Option<GameFinished>,
+}
+
+#[derive(Component)]
+pub struct ResultPanel;
+
+#[derive(SystemSet, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct ResultScreenSet;
+
+/// Ends a run on `GameFinished` and shows its result with Retry, Next Stage and Title buttons,
+/// along with the high scores. Leaving the result screen despawns everything but cameras and
+/// windows, so games set up again on entering `InGame`.
+pub struct ResultScreenPlugin;
+
+impl Plugin for ResultScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ResultScreen>()
+            .add_event::<GameFinished>()
+            .add_plugins(MenuPlugin::<ResultButton>::default())
+            .add_systems(Update, finish_game.run_if(in_state(AppState::InGame)))
+            .add_systems(
+                OnEnter(AppState::GameOver),
+                (spawn_result_screen, spawn_high_score_table),
+            )
+            .add_systems(
+                OnEnter(AppState::GameClear),
+                (spawn_result_screen, spawn_high_score_table),
+            )
+            .add_systems(
+                Update,
+                leave_result_screen
+                    .after(MenuSet)
+                    .in_set(ResultScreenSet)
+                    .run_if(in_state(AppState::GameOver).or_else(in_state(AppState::GameClear))),
+            )
+            .add_systems(
+                OnExit(AppState::GameOver),
+                (despawn_run, remove_result_menu),
+            )
+            .add_systems(
+                OnExit(AppState::GameClear),
+                (despawn_run, remove_result_menu),
+            );
+    }
+}
+
+fn finish_game(
+    mut finished_events: EventReader<GameFinished>,
+    mut result_screen: ResMut<ResultScreen>,
+    mut app_state: ResMut<NextState<AppState>>,
+) {
+    // Later ticks of the same frame may finish again, the first one counts
+    let Some(finished) = finished_events.read().next().cloned() else {
+        return;
+    };
+    finished_events.clear();
+
+    app_state.set(finished.outcome.state());
+    result_screen.finished = Some(finished);
+}
+
+fn spawn_result_screen(
+    mut commands: Commands,
+    locale: Res<Locale>,
+    theme: Res<Theme>,
+    app_state: Res<State<AppState>>,
+    mut result_screen: ResMut<ResultScreen>,
+    mut sfx_events: EventWriter<SfxEvent>,
+) {
+    // Games may also switch state without `GameFinished`, the state then tells the outcome
+    let outcome = match app_state.get() {
+        AppState::GameClear => Outcome::GameClear,
+        _ => Outcome::GameOver,
+    };
+    sfx_events.send(match outcome {
+        Outcome::GameOver => SfxEvent::GameOver,
+        Outcome::GameClear => SfxEvent::GameClear,
+    });
+    let finished = result_screen
+        .finished
+        .take()
+        .filter(|finished| finished.outcome == outcome)
+        .unwrap_or_else(|| GameFinished::new(outcome));
+
+    let menu = Menu::new(
+        [
+            Some(ResultButton::Retry),
+            finished.next_stage.then_some(ResultButton::NextStage),
+            Some(ResultButton::Title),
+        ]
+        .into_iter()
+        .flatten()
+        .collect(),
+        Some(ResultButton::NextStage),
+    );
+
+    let (heading, heading_color) = outcome.heading();
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            ResultPanel,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        padding: UiRect::all(RESULT_PADDING),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: RESULT_GAP,
+                        ..default()
+                    },
+                    background_color: theme.panel.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        locale.get(heading),
+                        locale.text_style(RESULT_FONT_SIZE, theme.color(heading_color)),
+                    ));
+
+                    if let Some(score) = finished.score {
+                        parent.spawn(TextBundle::from_section(
+                            format!("{}: {score}", locale.get("score")),
+                            locale.text_style(RESULT_FONT_SIZE_SMALL, theme.panel_text),
+                        ));
+                    }
+                    for (stat, value) in &finished.stats {
+                        parent.spawn(TextBundle::from_section(
+                            format!("{}: {value}", locale.get(stat.label())),
+                            locale.text_style(RESULT_FONT_SIZE_SMALL, theme.panel_text),
+                        ));
+                    }
+
+                    spawn_menu_buttons(parent, menu.buttons(), FlexDirection::Row, &locale, &theme);
+                });
+        });
+
+    commands.insert_resource(menu);
+}
+
+fn leave_result_screen(
+    mut chosen_events: EventReader<MenuChosen<ResultButton>>,
+    mut app_state: ResMut<NextState<AppState>>,
+) {
+    for MenuChosen(button) in chosen_events.read() {
+        app_state.set(match button {
+            ResultButton::Retry | ResultButton::NextStage => AppState::InGame,
+            ResultButton::Title => AppState::MainMenu,
+        });
+    }
+}
+
+fn remove_result_menu(mut commands: Commands) {
+    commands.remove_resource::<Menu<ResultButton>>();
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::launch;
+
+const SEED_OPTION: &str = "seed";
+
+/// The only source of randomness in the games. Re-seeded at the start of every run, so the same
+/// seed and the same inputs play out the same game.
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    rng: ChaCha8Rng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Seeded from `--seed <n>`, then `GAME_SEED`, then `?seed=<n>` on wasm, else at random.
+    pub fn from_env() -> Self {
+        Self::new(configured_seed().unwrap_or_else(rand::random))
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn reset(&mut self) {
+        self.rng = ChaCha8Rng::seed_from_u64(self.seed);
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+fn configured_seed() -> Option<u64> {
+    let seed = launch::option(SEED_OPTION)?;
+
+    match seed.trim().parse() {
+        Ok(seed) => Some(seed),
+        Err(error) => {
+            warn!("ignoring seed {seed:?}: {error}");
+            None
+        }
+    }
+}
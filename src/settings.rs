@@ -0,0 +1,594 @@
+use bevy::{
+    audio::GlobalVolume,
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+    window::{PresentMode, PrimaryWindow, WindowMode},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    input::{Action, InputBinding, InputMap, ResetInput},
+    locale::{Language, Locale, LocaleSet, LocalizedString, LocalizedText},
+    menu::{spawn_menu_buttons, Menu, MenuAction, MenuButton, MenuChosen, MenuPlugin, MenuSet},
+    storage,
+    theme::{Palette, Theme, ThemeColor, Themed},
+};
+
+const SETTINGS_FILE: &str = "settings.json";
+const DEFAULT_PLAYER_NAME: &str = "Player";
+// As much as the high score table shows
+const MAX_PLAYER_NAME_LENGTH: usize = 10;
+
+// Volumes go up in tenths and wrap around to mute
+const VOLUME_STEPS: f32 = 10.0;
+const UI_SCALES: [f32; 4] = [0.75, 1.0, 1.25, 1.5];
+
+const SETTINGS_PADDING: Val = Val::Px(40.0);
+const SETTINGS_GAP: Val = Val::Px(20.0);
+const SETTINGS_FONT_SIZE: f32 = 50.0;
+
+/// Options shared by every game, saved to `settings.json` (localStorage on wasm) whenever they
+/// change. Key bindings are saved per game by `InputMap`.
+#[derive(Resource, Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub struct Settings {
+    /// 0.0 to 1.0, like the other volumes.
+    pub master_volume: f32,
+    pub sfx_volume: f32,
+    pub music_volume: f32,
+    pub fullscreen: bool,
+    pub vsync: bool,
+    pub ui_scale: f32,
+    pub palette: Palette,
+    pub language: Language,
+    /// Put on new high scores.
+    pub player_name: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            sfx_volume: 1.0,
+            music_volume: 1.0,
+            fullscreen: false,
+            vsync: true,
+            ui_scale: 1.0,
+            palette: Palette::default(),
+            language: Language::default(),
+            player_name: DEFAULT_PLAYER_NAME.to_string(),
+        }
+    }
+}
+
+impl Settings {
+    /// The saved settings, or the defaults written out when there are none yet.
+    pub fn load() -> Self {
+        let Some(contents) = storage::load(SETTINGS_FILE) else {
+            let settings = Self::default();
+            settings.save();
+            return settings;
+        };
+
+        match serde_json::from_str(&contents) {
+            Ok(settings) => settings,
+            Err(error) => {
+                warn!("ignoring unreadable settings: {error}");
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string_pretty(self) {
+            Ok(contents) => storage::save(SETTINGS_FILE, &contents),
+            Err(error) => warn!("failed to serialize settings: {error}"),
+        }
+    }
+}
+
+fn next_volume(volume: f32) -> f32 {
+    let step = (volume * VOLUME_STEPS).round() + 1.0;
+    if step > VOLUME_STEPS {
+        0.0
+    } else {
+        step / VOLUME_STEPS
+    }
+}
+
+fn next_ui_scale(ui_scale: f32) -> f32 {
+    UI_SCALES
+        .iter()
+        .copied()
+        .find(|scale| *scale > ui_scale)
+        .unwrap_or(UI_SCALES[0])
+}
+
+fn percent(value: f32) -> String {
+    format!("{}%", (value * 100.0).round())
+}
+
+fn on_off(value: bool) -> &'static str {
+    if value {
+        "on"
+    } else {
+        "off"
+    }
+}
+
+fn action_name(action: Action) -> &'static str {
+    match action {
+        Action::MoveLeft => "action_move_left",
+        Action::MoveRight => "action_move_right",
+        Action::MoveUp => "action_move_up",
+        Action::MoveDown => "action_move_down",
+        Action::Jump => "action_jump",
+        Action::Fire => "action_fire",
+        Action::Hit => "action_hit",
+        Action::Confirm => "action_confirm",
+        Action::Retry => "action_retry",
+        Action::NextStage => "action_next_stage",
+        Action::Pause => "action_pause",
+    }
+}
+
+/// Whether the settings are shown, over the hub, a title screen or the pause menu. Whichever
+/// opened them takes its menu back once they are `Closed` again.
+#[derive(States, Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+pub enum SettingsScreen {
+    #[default]
+    Closed,
+    Open,
+}
+
+#[derive(Resource, Clone, Copy, Eq, PartialEq, Debug)]
+enum SettingsPage {
+    Main,
+    Audio,
+    Display,
+    Bindings,
+}
+
+/// Each setting is a button that steps to its next value when chosen.
+#[derive(Component, Clone, Copy, Eq, PartialEq, Debug)]
+pub enum SettingsButton {
+    Audio,
+    Display,
+    KeyBindings,
+    Language,
+    MasterVolume,
+    SfxVolume,
+    MusicVolume,
+    Fullscreen,
+    Vsync,
+    UiScale,
+    Palette,
+    /// Takes typed letters until Enter or Esc.
+    PlayerName,
+    /// Waits for the next key and binds it to the action, in place of its other keys.
+    Binding(Action),
+    Back,
+}
+
+impl MenuButton for SettingsButton {
+    fn label(self) -> LocalizedString {
+        match self {
+            SettingsButton::Audio => "audio",
+            SettingsButton::Display => "display",
+            SettingsButton::KeyBindings => "key_bindings",
+            SettingsButton::Language => "language",
+            SettingsButton::MasterVolume => "master_volume",
+            SettingsButton::SfxVolume => "sfx_volume",
+            SettingsButton::MusicVolume => "music_volume",
+            SettingsButton::Fullscreen => "window",
+            SettingsButton::Vsync => "vsync",
+            SettingsButton::UiScale => "ui_scale",
+            SettingsButton::Palette => "palette",
+            SettingsButton::PlayerName => "player_name",
+            SettingsButton::Binding(action) => action_name(action),
+            SettingsButton::Back => "back",
+        }
+        .into()
+    }
+}
+
+impl SettingsButton {
+    fn text(
+        self,
+        settings: &Settings,
+        locale: &Locale,
+        input_map: Option<&InputMap<Action>>,
+        rebinding: &Rebinding,
+        naming: &Naming,
+    ) -> String {
+        let value = match self {
+            SettingsButton::Language => locale.get(settings.language.label()).to_string(),
+            SettingsButton::MasterVolume => percent(settings.master_volume),
+            SettingsButton::SfxVolume => percent(settings.sfx_volume),
+            SettingsButton::MusicVolume => percent(settings.music_volume),
+            SettingsButton::Fullscreen if settings.fullscreen => {
+                locale.get("fullscreen").to_string()
+            }
+            SettingsButton::Fullscreen => locale.get("windowed").to_string(),
+            SettingsButton::Vsync => locale.get(on_off(settings.vsync)).to_string(),
+            SettingsButton::UiScale => percent(settings.ui_scale),
+            SettingsButton::Palette => locale.get(settings.palette.label()).to_string(),
+            SettingsButton::PlayerName if naming.0 => format!("{}_", settings.player_name),
+            SettingsButton::PlayerName => settings.player_name.clone(),
+            SettingsButton::Binding(action) if rebinding.0 == Some(action) => {
+                locale.get("press_a_key").to_string()
+            }
+            SettingsButton::Binding(action) => {
+                input_map.map_or_else(|| "-".to_string(), |input_map| input_map.label(action))
+            }
+            _ => return locale.text(self.label()).to_string(),
+        };
+        format!("{}: {value}", locale.text(self.label()))
+    }
+}
+
+/// The action waiting for a key on the key bindings page.
+#[derive(Resource, Default, Debug)]
+struct Rebinding(Option<Action>);
+
+/// Whether the player name is being typed.
+#[derive(Resource, Default, Debug)]
+struct Naming(bool);
+
+#[derive(Component)]
+pub struct SettingsPanel;
+
+/// Loads `Settings` at startup and applies them to the window, the UI and the audio whenever
+/// they change. The Settings buttons of the hub, the title screens and the pause menu open the
+/// `SettingsScreen`, which edits them along with the running game's key bindings and the
+/// language of the `Locale`.
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Settings::load())
+            .init_resource::<Rebinding>()
+            .init_resource::<Naming>()
+            .init_state::<SettingsScreen>()
+            .add_plugins(MenuPlugin::<SettingsButton>::default())
+            .add_systems(Update, apply_settings.run_if(resource_changed::<Settings>))
+            .add_systems(OnEnter(SettingsScreen::Open), open_settings)
+            .add_systems(
+                Update,
+                (
+                    // Take the key before the menu could move or choose with it
+                    (capture_binding, type_player_name).before(MenuSet),
+                    (change_setting, spawn_settings_page).chain().after(MenuSet),
+                )
+                    .run_if(in_state(SettingsScreen::Open)),
+            )
+            // Once the button labels are in the new language
+            .add_systems(
+                PostUpdate,
+                update_settings_text
+                    .after(LocaleSet)
+                    .run_if(in_state(SettingsScreen::Open)),
+            )
+            .add_systems(OnExit(SettingsScreen::Open), close_settings);
+    }
+}
+
+fn apply_settings(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    // Sound effects and music scale their own volume by `sfx_volume` and `music_volume`
+    commands.insert_resource(GlobalVolume::new(settings.master_volume));
+    commands.insert_resource(UiScale(settings.ui_scale));
+
+    for mut window in &mut window_query {
+        window.mode = if settings.fullscreen {
+            WindowMode::BorderlessFullscreen
+        } else {
+            WindowMode::Windowed
+        };
+        window.present_mode = if settings.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        };
+    }
+}
+
+fn open_settings(mut commands: Commands) {
+    commands.insert_resource(SettingsPage::Main);
+}
+
+fn close_settings(
+    mut commands: Commands,
+    panel_query: Query<Entity, With<SettingsPanel>>,
+    mut rebinding: ResMut<Rebinding>,
+    mut naming: ResMut<Naming>,
+) {
+    for panel_entity in &panel_query {
+        commands.entity(panel_entity).despawn_recursive();
+    }
+    commands.remove_resource::<SettingsPage>();
+    commands.remove_resource::<Menu<SettingsButton>>();
+    rebinding.0 = None;
+    naming.0 = false;
+}
+
+fn capture_binding(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut rebinding: ResMut<Rebinding>,
+    input_map: Option<ResMut<InputMap<Action>>>,
+    mut menu_input: ResMut<ButtonInput<MenuAction>>,
+    mut reset_input: ResetInput,
+) {
+    // Read every frame, so the press that started rebinding is never taken for the new key
+    let pressed = keyboard_events
+        .read()
+        .filter(|event| event.state.is_pressed())
+        .map(|event| event.key_code)
+        .last();
+    let Some(action) = rebinding.0 else {
+        return;
+    };
+
+    // Nothing reaches the menu while waiting for the key
+    menu_input.reset_all();
+    let Some(key_code) = pressed else {
+        return;
+    };
+
+    // Esc cancels
+    if key_code != KeyCode::Escape {
+        if let Some(mut input_map) = input_map {
+            // The new key comes first, as on-screen prompts show the first binding
+            let bindings = std::iter::once(InputBinding::Key(key_code))
+                .chain(
+                    input_map
+                        .bindings(action)
+                        .iter()
+                        .filter(|binding| !matches!(binding, InputBinding::Key(_)))
+                        .copied(),
+                )
+                .collect();
+            input_map.rebind(action, bindings);
+            input_map.save();
+        }
+    }
+    rebinding.0 = None;
+    reset_input.reset_all();
+}
+
+fn type_player_name(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut naming: ResMut<Naming>,
+    mut settings: ResMut<Settings>,
+    mut menu_input: ResMut<ButtonInput<MenuAction>>,
+    mut reset_input: ResetInput,
+) {
+    // Read every frame, so the press that started naming is never typed
+    let pressed: Vec<Key> = keyboard_events
+        .read()
+        .filter(|event| event.state.is_pressed())
+        .map(|event| event.logical_key.clone())
+        .collect();
+    if !naming.0 {
+        return;
+    }
+
+    menu_input.reset_all();
+    for key in pressed {
+        match key {
+            Key::Character(text) => {
+                for character in text.chars().filter(|character| !character.is_control()) {
+                    if settings.player_name.chars().count() < MAX_PLAYER_NAME_LENGTH {
+                        settings.player_name.push(character);
+                    }
+                }
+            }
+            Key::Space if settings.player_name.chars().count() < MAX_PLAYER_NAME_LENGTH => {
+                settings.player_name.push(' ');
+            }
+            Key::Backspace => {
+                settings.player_name.pop();
+            }
+            Key::Enter | Key::Escape => {
+                let name = settings.player_name.trim().to_string();
+                settings.player_name = if name.is_empty() {
+                    DEFAULT_PLAYER_NAME.to_string()
+                } else {
+                    name
+                };
+                naming.0 = false;
+                reset_input.reset_all();
+                return;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn change_setting(
+    menu_input: Res<ButtonInput<MenuAction>>,
+    mut chosen_events: EventReader<MenuChosen<SettingsButton>>,
+    mut settings: ResMut<Settings>,
+    mut page: ResMut<SettingsPage>,
+    mut rebinding: ResMut<Rebinding>,
+    mut naming: ResMut<Naming>,
+    mut settings_screen: ResMut<NextState<SettingsScreen>>,
+) {
+    let back = menu_input
+        .just_pressed(MenuAction::Pause)
+        .then_some(SettingsButton::Back);
+
+    for button in chosen_events
+        .read()
+        .map(|MenuChosen(button)| *button)
+        .chain(back)
+    {
+        match button {
+            SettingsButton::Audio => *page = SettingsPage::Audio,
+            SettingsButton::Display => *page = SettingsPage::Display,
+            SettingsButton::KeyBindings => *page = SettingsPage::Bindings,
+            SettingsButton::Language => settings.language = settings.language.next(),
+            SettingsButton::MasterVolume => {
+                settings.master_volume = next_volume(settings.master_volume);
+            }
+            SettingsButton::SfxVolume => settings.sfx_volume = next_volume(settings.sfx_volume),
+            SettingsButton::MusicVolume => {
+                settings.music_volume = next_volume(settings.music_volume);
+            }
+            SettingsButton::Fullscreen => settings.fullscreen = !settings.fullscreen,
+            SettingsButton::Vsync => settings.vsync = !settings.vsync,
+            SettingsButton::UiScale => settings.ui_scale = next_ui_scale(settings.ui_scale),
+            SettingsButton::Palette => settings.palette = settings.palette.next(),
+            SettingsButton::PlayerName => naming.0 = true,
+            SettingsButton::Binding(action) => rebinding.0 = Some(action),
+            SettingsButton::Back if *page == SettingsPage::Main => {
+                settings_screen.set(SettingsScreen::Closed);
+            }
+            SettingsButton::Back => *page = SettingsPage::Main,
+        }
+    }
+
+    if settings.is_changed() {
+        settings.save();
+    }
+}
+
+fn spawn_settings_page(
+    mut commands: Commands,
+    locale: Res<Locale>,
+    theme: Res<Theme>,
+    page: Res<SettingsPage>,
+    input_map: Option<Res<InputMap<Action>>>,
+    panel_query: Query<Entity, With<SettingsPanel>>,
+) {
+    if !page.is_changed() {
+        return;
+    }
+
+    for panel_entity in &panel_query {
+        commands.entity(panel_entity).despawn_recursive();
+    }
+
+    let buttons = match *page {
+        // Only a game has key bindings, the hub has none
+        SettingsPage::Main => [
+            SettingsButton::Audio,
+            SettingsButton::Display,
+            SettingsButton::KeyBindings,
+            SettingsButton::Language,
+            SettingsButton::PlayerName,
+        ]
+        .into_iter()
+        .filter(|button| *button != SettingsButton::KeyBindings || input_map.is_some())
+        .collect(),
+        SettingsPage::Audio => vec![
+            SettingsButton::MasterVolume,
+            SettingsButton::SfxVolume,
+            SettingsButton::MusicVolume,
+        ],
+        SettingsPage::Display => vec![
+            SettingsButton::Fullscreen,
+            SettingsButton::Vsync,
+            SettingsButton::UiScale,
+            SettingsButton::Palette,
+        ],
+        SettingsPage::Bindings => {
+            let mut actions: Vec<Action> = input_map
+                .iter()
+                .flat_map(|input_map| input_map.actions())
+                .collect();
+            actions.sort_by_key(|action| *action as u8);
+            actions.into_iter().map(SettingsButton::Binding).collect()
+        }
+    };
+    let menu = Menu::new(
+        buttons.into_iter().chain([SettingsButton::Back]).collect(),
+        None,
+    );
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: theme.backdrop.into(),
+                ..default()
+            },
+            // A palette chosen here recolours the page right away
+            Themed(ThemeColor::Backdrop),
+            SettingsPanel,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            padding: UiRect::all(SETTINGS_PADDING),
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            row_gap: SETTINGS_GAP,
+                            ..default()
+                        },
+                        background_color: theme.panel.into(),
+                        ..default()
+                    },
+                    Themed(ThemeColor::Panel),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            locale.get("settings"),
+                            locale.text_style(SETTINGS_FONT_SIZE, theme.panel_text),
+                        ),
+                        LocalizedText::new("settings"),
+                        Themed(ThemeColor::PanelText),
+                    ));
+
+                    spawn_menu_buttons(
+                        parent,
+                        menu.buttons(),
+                        FlexDirection::Column,
+                        &locale,
+                        &theme,
+                    );
+                });
+        });
+
+    commands.insert_resource(menu);
+}
+
+// Buttons show their current value, and the bindings page which action waits for a key
+fn update_settings_text(
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+    input_map: Option<Res<InputMap<Action>>>,
+    rebinding: Res<Rebinding>,
+    naming: Res<Naming>,
+    button_query: Query<(&SettingsButton, &Children)>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (button, children) in &button_query {
+        let value = button.text(
+            &settings,
+            &locale,
+            input_map.as_deref(),
+            &rebinding,
+            &naming,
+        );
+
+        let mut texts = text_query.iter_many_mut(children);
+        while let Some(mut text) = texts.fetch_next() {
+            if text.sections[0].value != value {
+                text.sections[0].value.clone_from(&value);
+            }
+        }
+    }
+}
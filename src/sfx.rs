@@ -0,0 +1,130 @@
+use bevy::{audio::Volume, prelude::*};
+use rand::Rng;
+
+use crate::{loading::GameSounds, settings::Settings};
+
+const JUMP_SOUND: &str = "sounds/jump.wav";
+const HIT_SOUND: &str = "sounds/breakout_collision.ogg";
+const PICKUP_SOUND: &str = "sounds/pickup.wav";
+const SHOOT_SOUND: &str = "sounds/shoot.wav";
+const EXPLODE_SOUND: &str = "sounds/explode.wav";
+const POP_SOUND: &str = "sounds/pop.wav";
+const TIMING_SOUND: &str = "sounds/timing.ogg";
+const UI_SELECT_SOUND: &str = "sounds/ui_select.wav";
+const UI_CONFIRM_SOUND: &str = "sounds/ui_confirm.wav";
+const GAME_OVER_SOUND: &str = "sounds/game_over.wav";
+const GAME_CLEAR_SOUND: &str = "sounds/game_clear.wav";
+
+/// A sound effect for the game to play. Sent from any schedule, `FixedUpdate` included.
+#[derive(Event, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum SfxEvent {
+    Jump,
+    Hit,
+    Pickup,
+    Shoot,
+    Explode,
+    Pop,
+    Timing,
+    UiSelect,
+    UiConfirm,
+    GameOver,
+    GameClear,
+}
+
+/// How one `SfxEvent` sounds.
+#[derive(Clone, Copy, Debug)]
+pub struct SfxSound {
+    pub path: &'static str,
+    pub volume: f32,
+    /// Playback speed, which also sets the pitch.
+    pub speed: f32,
+    /// Each copy plays up to this much faster or slower, so repeats don't sound canned.
+    pub speed_variation: f32,
+    /// Copies of this effect that may play at once, later ones are dropped.
+    pub max_playing: usize,
+}
+
+impl SfxEvent {
+    pub const ALL: [SfxEvent; 11] = [
+        SfxEvent::Jump,
+        SfxEvent::Hit,
+        SfxEvent::Pickup,
+        SfxEvent::Shoot,
+        SfxEvent::Explode,
+        SfxEvent::Pop,
+        SfxEvent::Timing,
+        SfxEvent::UiSelect,
+        SfxEvent::UiConfirm,
+        SfxEvent::GameOver,
+        SfxEvent::GameClear,
+    ];
+
+    pub fn sound(self) -> SfxSound {
+        let sound = |path, volume, speed, speed_variation, max_playing| SfxSound {
+            path,
+            volume,
+            speed,
+            speed_variation,
+            max_playing,
+        };
+
+        match self {
+            SfxEvent::Jump => sound(JUMP_SOUND, 0.4, 1.0, 0.1, 2),
+            SfxEvent::Hit => sound(HIT_SOUND, 0.6, 1.0, 0.1, 4),
+            SfxEvent::Pickup => sound(PICKUP_SOUND, 0.5, 1.0, 0.05, 4),
+            SfxEvent::Shoot => sound(SHOOT_SOUND, 0.3, 1.0, 0.15, 4),
+            SfxEvent::Explode => sound(EXPLODE_SOUND, 0.8, 1.0, 0.05, 2),
+            SfxEvent::Pop => sound(POP_SOUND, 0.5, 1.0, 0.2, 6),
+            SfxEvent::Timing => sound(TIMING_SOUND, 0.5, 1.0, 0.0, 2),
+            SfxEvent::UiSelect => sound(UI_SELECT_SOUND, 0.3, 1.0, 0.0, 1),
+            SfxEvent::UiConfirm => sound(UI_CONFIRM_SOUND, 0.5, 1.0, 0.0, 1),
+            SfxEvent::GameOver => sound(GAME_OVER_SOUND, 0.8, 1.0, 0.0, 1),
+            SfxEvent::GameClear => sound(GAME_CLEAR_SOUND, 0.8, 1.0, 0.0, 1),
+        }
+    }
+}
+
+/// Marks a playing sound effect.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Sfx(pub SfxEvent);
+
+/// Plays every `SfxEvent` at the SFX volume of the `Settings`.
+pub struct SfxPlugin;
+
+impl Plugin for SfxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SfxEvent>()
+            .add_systems(PostUpdate, play_sfx);
+    }
+}
+
+fn play_sfx(
+    mut commands: Commands,
+    mut sfx_events: EventReader<SfxEvent>,
+    sounds: Res<GameSounds>,
+    settings: Res<Settings>,
+    sfx_query: Query<&Sfx>,
+) {
+    let mut rng = rand::thread_rng();
+    let mut playing: Vec<SfxEvent> = sfx_query.iter().map(|Sfx(event)| *event).collect();
+
+    for event in sfx_events.read() {
+        let sound = event.sound();
+        if playing.iter().filter(|other| *other == event).count() >= sound.max_playing {
+            continue;
+        }
+        playing.push(*event);
+
+        // Not from `GameRng`, which only the game itself may draw from
+        let speed = sound.speed + rng.gen_range(-1.0..=1.0) * sound.speed_variation;
+        commands.spawn((
+            AudioBundle {
+                source: sounds.get(sound.path),
+                settings: PlaybackSettings::DESPAWN
+                    .with_volume(Volume::new(sound.volume * settings.sfx_volume))
+                    .with_speed(speed),
+            },
+            Sfx(*event),
+        ));
+    }
+}
@@ -0,0 +1,63 @@
+use bevy::{math::bounding::Aabb2d, prelude::*, utils::HashMap};
+
+pub const DEFAULT_CELL_SIZE: f32 = 64.0;
+
+/// Uniform grid broadphase. Every collider is bucketed into each cell its bounding box covers.
+#[derive(Resource, Debug)]
+pub struct SpatialHash {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<Entity>>,
+}
+
+impl Default for SpatialHash {
+    fn default() -> Self {
+        Self::new(DEFAULT_CELL_SIZE)
+    }
+}
+
+impl SpatialHash {
+    pub fn new(cell_size: f32) -> Self {
+        assert!(cell_size > 0.0, "spatial hash cell size must be positive");
+
+        Self {
+            cell_size,
+            cells: HashMap::default(),
+        }
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    pub fn insert(&mut self, entity: Entity, aabb: Aabb2d) {
+        let (min, max) = self.cell_range(aabb);
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(entity);
+            }
+        }
+    }
+
+    /// Entities sharing a cell with `aabb`. One that spans several of those cells is yielded once per cell.
+    pub fn query(&self, aabb: Aabb2d) -> impl Iterator<Item = Entity> + '_ {
+        let (min, max) = self.cell_range(aabb);
+
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+    }
+
+    fn cell_range(&self, aabb: Aabb2d) -> (IVec2, IVec2) {
+        (
+            (aabb.min / self.cell_size).floor().as_ivec2(),
+            (aabb.max / self.cell_size).floor().as_ivec2(),
+        )
+    }
+}
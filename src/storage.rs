@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+const APP_NAME: &str = "ittokun-bevy-games";
+
+// Native: one file per name under the XDG data dir (or the platform equivalent)
+#[cfg(not(target_arch = "wasm32"))]
+fn path(name: &str) -> Option<std::path::PathBuf> {
+    Some(dirs::data_dir()?.join(APP_NAME).join(name))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load(name: &str) -> Option<String> {
+    let path = path(name)?;
+
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => Some(contents),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => None,
+        Err(error) => {
+            warn!("failed to read {}: {error}", path.display());
+            None
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save(name: &str, contents: &str) {
+    let Some(path) = path(name) else {
+        warn!("no data directory to save {name} in");
+        return;
+    };
+    let result = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| std::fs::write(&path, contents));
+
+    if let Err(error) = result {
+        warn!("failed to write {}: {error}", path.display());
+    }
+}
+
+// Wasm: one localStorage item per name
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn load(name: &str) -> Option<String> {
+    local_storage()?
+        .get_item(&format!("{APP_NAME}/{name}"))
+        .ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn save(name: &str, contents: &str) {
+    let saved = local_storage()
+        .map(|storage| {
+            storage
+                .set_item(&format!("{APP_NAME}/{name}"), contents)
+                .is_ok()
+        })
+        .unwrap_or(false);
+
+    if !saved {
+        warn!("failed to save {name} to localStorage");
+    }
+}
@@ -0,0 +1,406 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::settings::Settings;
+
+/// A set of colours for the games, chosen in the `Settings`. The colour-blind ones keep what
+/// must be told apart, like good and bad drops, in colours that still differ for that kind of
+/// colour blindness.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default, Serialize, Deserialize)]
+pub enum Palette {
+    #[default]
+    Default,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+    HighContrast,
+}
+
+impl Palette {
+    pub const ALL: [Palette; 5] = [
+        Palette::Default,
+        Palette::Deuteranopia,
+        Palette::Protanopia,
+        Palette::Tritanopia,
+        Palette::HighContrast,
+    ];
+
+    /// The catalog key of its name.
+    pub fn label(self) -> &'static str {
+        match self {
+            Palette::Default => "palette_default",
+            Palette::Deuteranopia => "palette_deuteranopia",
+            Palette::Protanopia => "palette_protanopia",
+            Palette::Tritanopia => "palette_tritanopia",
+            Palette::HighContrast => "palette_high_contrast",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|palette| *palette == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// What a colour means, the same in every game.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum ThemeColor {
+    Background,
+    /// Behind games set in space or at night.
+    DarkBackground,
+    Player,
+    /// Opponents, targets and what they fire.
+    Enemy,
+    /// What is worth catching.
+    Good,
+    /// What hurts or costs points.
+    Bad,
+    /// What only blocks the way.
+    Obstacle,
+    Ground,
+    Goal,
+    Text,
+    /// The values next to `Text`.
+    Score,
+    /// `Text` on the `DarkBackground`.
+    LightText,
+    /// `Score` on the `DarkBackground`.
+    LightScore,
+    /// The zones of a target, from the widest to the narrowest.
+    Zone(usize),
+    /// Behind the result, pause and settings panels.
+    Panel,
+    /// `Text` on a `Panel`, and the hub's title.
+    PanelText,
+    /// Dims the game behind a `Panel`.
+    Backdrop,
+    /// Behind the high score table, over a title screen.
+    Overlay,
+    Button,
+    ButtonSelected,
+    ButtonText,
+    /// The controls on a hub card, under its `ButtonText`.
+    ButtonHint,
+    /// The heading of a cleared game.
+    Cleared,
+    /// The heading of a game that is over.
+    Failed,
+    /// The latest high score in the table.
+    Highlight,
+    /// What went wrong, like an asset that failed to load.
+    Error,
+    ProgressBar,
+    ProgressFill,
+}
+
+/// A game's own colours, which a `Theme` keeps where its palette has none of its own.
+pub type GameColors = &'static [(ThemeColor, Color)];
+
+/// The colours of the `Palette` in the `Settings`, along with those of the game being played.
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+pub struct Theme {
+    pub palette: Palette,
+    pub background: Color,
+    pub dark_background: Color,
+    pub player: Color,
+    pub enemy: Color,
+    pub good: Color,
+    pub bad: Color,
+    pub obstacle: Color,
+    pub ground: Color,
+    pub goal: Color,
+    pub text: Color,
+    pub score: Color,
+    pub light_text: Color,
+    pub light_score: Color,
+    pub zones: [Color; 4],
+    pub panel: Color,
+    pub panel_text: Color,
+    pub backdrop: Color,
+    pub overlay: Color,
+    pub button: Color,
+    pub button_selected: Color,
+    pub button_text: Color,
+    pub button_hint: Color,
+    pub cleared: Color,
+    pub failed: Color,
+    pub highlight: Color,
+    pub error: Color,
+    pub progress_bar: Color,
+    pub progress_fill: Color,
+    game_colors: GameColors,
+}
+
+const DEFAULT_THEME: Theme = Theme {
+    palette: Palette::Default,
+    background: Color::srgb(0.9, 0.9, 0.9),
+    dark_background: Color::srgb(0.1, 0.1, 0.1),
+    player: Color::srgb(0.1, 0.8, 0.1),
+    enemy: Color::srgb(0.9, 0.3, 0.3),
+    good: Color::srgb(0.1, 0.1, 0.8),
+    bad: Color::srgb(0.8, 0.1, 0.1),
+    obstacle: Color::srgb(0.8, 0.7, 0.6),
+    ground: Color::srgb(0.5, 0.3, 0.2),
+    goal: Color::srgb(0.8, 0.8, 0.2),
+    text: Color::srgb(0.3, 0.3, 0.3),
+    score: Color::srgb(0.5, 0.5, 0.5),
+    light_text: Color::srgb(0.9, 0.9, 0.9),
+    light_score: Color::srgb(0.7, 0.7, 0.7),
+    zones: [
+        Color::srgb(0.8, 0.8, 0.8),
+        Color::srgb(0.7, 0.7, 0.7),
+        Color::srgb(0.6, 0.6, 0.6),
+        Color::srgb(0.5, 0.5, 0.5),
+    ],
+    panel: Color::srgb(0.8, 0.8, 0.8),
+    panel_text: Color::srgb(0.4, 0.4, 0.4),
+    backdrop: Color::srgba(0.0, 0.0, 0.0, 0.5),
+    overlay: Color::srgba(0.1, 0.1, 0.1, 0.8),
+    button: Color::srgb(0.6, 0.6, 0.6),
+    button_selected: Color::srgb(0.3, 0.3, 0.3),
+    button_text: Color::srgb(0.95, 0.95, 0.95),
+    button_hint: Color::srgb(0.85, 0.85, 0.6),
+    cleared: Color::srgb(0.1, 0.8, 0.1),
+    failed: Color::srgb(0.9, 0.1, 0.1),
+    highlight: Color::srgb(1.0, 0.8, 0.2),
+    error: Color::srgb(0.9, 0.3, 0.3),
+    progress_bar: Color::srgb(0.3, 0.3, 0.3),
+    progress_fill: Color::srgb(0.1, 0.8, 0.1),
+    game_colors: &[],
+};
+
+// Blue against orange, from the Okabe-Ito colours
+const DEUTERANOPIA_THEME: Theme = Theme {
+    palette: Palette::Deuteranopia,
+    player: Color::srgb(0.34, 0.71, 0.91),
+    enemy: Color::srgb(0.9, 0.6, 0.0),
+    good: Color::srgb(0.0, 0.45, 0.7),
+    bad: Color::srgb(0.84, 0.37, 0.0),
+    goal: Color::srgb(0.94, 0.89, 0.26),
+    cleared: Color::srgb(0.0, 0.45, 0.7),
+    failed: Color::srgb(0.84, 0.37, 0.0),
+    error: Color::srgb(0.84, 0.37, 0.0),
+    progress_fill: Color::srgb(0.34, 0.71, 0.91),
+    ..DEFAULT_THEME
+};
+
+// Reds look dark, so the warm colours lean to yellow
+const PROTANOPIA_THEME: Theme = Theme {
+    palette: Palette::Protanopia,
+    player: Color::srgb(0.34, 0.71, 0.91),
+    enemy: Color::srgb(0.94, 0.89, 0.26),
+    good: Color::srgb(0.0, 0.45, 0.7),
+    bad: Color::srgb(0.9, 0.6, 0.0),
+    goal: Color::srgb(0.94, 0.89, 0.26),
+    cleared: Color::srgb(0.0, 0.45, 0.7),
+    failed: Color::srgb(0.9, 0.6, 0.0),
+    error: Color::srgb(0.9, 0.6, 0.0),
+    progress_fill: Color::srgb(0.34, 0.71, 0.91),
+    ..DEFAULT_THEME
+};
+
+// Blue and green look alike, so is yellow and pink, but red stands out from both
+const TRITANOPIA_THEME: Theme = Theme {
+    palette: Palette::Tritanopia,
+    player: Color::srgb(0.0, 0.62, 0.45),
+    enemy: Color::srgb(0.84, 0.37, 0.0),
+    good: Color::srgb(0.0, 0.6, 0.7),
+    bad: Color::srgb(0.8, 0.1, 0.1),
+    goal: Color::srgb(0.8, 0.47, 0.65),
+    ..DEFAULT_THEME
+};
+
+const HIGH_CONTRAST_THEME: Theme = Theme {
+    palette: Palette::HighContrast,
+    background: Color::WHITE,
+    dark_background: Color::BLACK,
+    player: Color::srgb(0.0, 0.7, 0.0),
+    enemy: Color::srgb(1.0, 0.4, 0.0),
+    good: Color::srgb(0.1, 0.3, 1.0),
+    bad: Color::srgb(0.9, 0.0, 0.0),
+    obstacle: Color::WHITE,
+    ground: Color::BLACK,
+    goal: Color::srgb(1.0, 0.8, 0.0),
+    text: Color::BLACK,
+    score: Color::BLACK,
+    light_text: Color::WHITE,
+    light_score: Color::srgb(1.0, 1.0, 0.0),
+    zones: [
+        Color::srgb(0.85, 0.85, 0.85),
+        Color::srgb(0.6, 0.6, 0.6),
+        Color::srgb(0.35, 0.35, 0.35),
+        Color::BLACK,
+    ],
+    panel: Color::WHITE,
+    panel_text: Color::BLACK,
+    backdrop: Color::srgba(0.0, 0.0, 0.0, 0.8),
+    overlay: Color::srgba(0.0, 0.0, 0.0, 0.9),
+    button: Color::BLACK,
+    button_selected: Color::srgb(0.1, 0.3, 1.0),
+    button_text: Color::WHITE,
+    button_hint: Color::srgb(1.0, 1.0, 0.0),
+    cleared: Color::srgb(0.0, 0.6, 0.0),
+    failed: Color::srgb(0.9, 0.0, 0.0),
+    highlight: Color::srgb(1.0, 1.0, 0.0),
+    error: Color::srgb(1.0, 0.4, 0.4),
+    progress_bar: Color::WHITE,
+    progress_fill: Color::srgb(0.0, 0.7, 0.0),
+    ..DEFAULT_THEME
+};
+
+impl Theme {
+    pub fn new(palette: Palette) -> Self {
+        match palette {
+            Palette::Default => DEFAULT_THEME,
+            Palette::Deuteranopia => DEUTERANOPIA_THEME,
+            Palette::Protanopia => PROTANOPIA_THEME,
+            Palette::Tritanopia => TRITANOPIA_THEME,
+            Palette::HighContrast => HIGH_CONTRAST_THEME,
+        }
+    }
+
+    /// The theme of the same palette with a game's own colours, in place of those the palette
+    /// leaves as they are in `Palette::Default`.
+    pub fn with_game_colors(&self, game_colors: GameColors) -> Self {
+        let mut theme = Theme::new(self.palette);
+        theme.game_colors = game_colors;
+        for (color, game_color) in game_colors {
+            if theme.color(*color) == DEFAULT_THEME.color(*color) {
+                *theme.color_mut(*color) = *game_color;
+            }
+        }
+        theme
+    }
+
+    pub fn color(&self, color: ThemeColor) -> Color {
+        let mut theme = *self;
+        *theme.color_mut(color)
+    }
+
+    fn color_mut(&mut self, color: ThemeColor) -> &mut Color {
+        match color {
+            ThemeColor::Background => &mut self.background,
+            ThemeColor::DarkBackground => &mut self.dark_background,
+            ThemeColor::Player => &mut self.player,
+            ThemeColor::Enemy => &mut self.enemy,
+            ThemeColor::Good => &mut self.good,
+            ThemeColor::Bad => &mut self.bad,
+            ThemeColor::Obstacle => &mut self.obstacle,
+            ThemeColor::Ground => &mut self.ground,
+            ThemeColor::Goal => &mut self.goal,
+            ThemeColor::Text => &mut self.text,
+            ThemeColor::Score => &mut self.score,
+            ThemeColor::LightText => &mut self.light_text,
+            ThemeColor::LightScore => &mut self.light_score,
+            ThemeColor::Zone(index) => {
+                let last = self.zones.len() - 1;
+                &mut self.zones[index.min(last)]
+            }
+            ThemeColor::Panel => &mut self.panel,
+            ThemeColor::PanelText => &mut self.panel_text,
+            ThemeColor::Backdrop => &mut self.backdrop,
+            ThemeColor::Overlay => &mut self.overlay,
+            ThemeColor::Button => &mut self.button,
+            ThemeColor::ButtonSelected => &mut self.button_selected,
+            ThemeColor::ButtonText => &mut self.button_text,
+            ThemeColor::ButtonHint => &mut self.button_hint,
+            ThemeColor::Cleared => &mut self.cleared,
+            ThemeColor::Failed => &mut self.failed,
+            ThemeColor::Highlight => &mut self.highlight,
+            ThemeColor::Error => &mut self.error,
+            ThemeColor::ProgressBar => &mut self.progress_bar,
+            ThemeColor::ProgressFill => &mut self.progress_fill,
+        }
+    }
+
+    // Whichever background the clear colour was in the theme before
+    fn background_of(&self, color: Color) -> Option<ThemeColor> {
+        [ThemeColor::Background, ThemeColor::DarkBackground]
+            .into_iter()
+            .find(|background| self.color(*background) == color)
+    }
+}
+
+impl FromWorld for Theme {
+    fn from_world(world: &mut World) -> Self {
+        world
+            .get_resource::<Settings>()
+            .map_or(DEFAULT_THEME, |settings| Theme::new(settings.palette))
+    }
+}
+
+/// Recolours a sprite, the material of a mesh, the background of a UI node or every section of a
+/// text when the `Theme` changes. Other texts keep the colours they were spawned with until they
+/// are spawned again.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Themed(pub ThemeColor);
+
+/// Keeps the `Theme` on the palette in the `Settings`, recolouring the `ClearColor` and every
+/// `Themed` entity when it changes.
+pub struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Theme>().add_systems(
+            PostUpdate,
+            (
+                change_theme.run_if(resource_changed::<Settings>),
+                apply_theme.run_if(resource_changed::<Theme>),
+            )
+                .chain(),
+        );
+    }
+}
+
+fn change_theme(settings: Res<Settings>, mut theme: ResMut<Theme>) {
+    let game_colors = theme.game_colors;
+    theme.set_if_neq(Theme::new(settings.palette).with_game_colors(game_colors));
+}
+
+fn apply_theme(
+    theme: Res<Theme>,
+    mut last_theme: Local<Option<Theme>>,
+    clear_color: Option<ResMut<ClearColor>>,
+    mut sprite_query: Query<(&Themed, &mut Sprite)>,
+    mut node_query: Query<(&Themed, &mut BackgroundColor)>,
+    mut text_query: Query<(&Themed, &mut Text)>,
+    color_material_query: Query<(&Themed, &Handle<ColorMaterial>)>,
+    standard_material_query: Query<(&Themed, &Handle<StandardMaterial>)>,
+    mut color_materials: ResMut<Assets<ColorMaterial>>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Everything was spawned in the first theme
+    let Some(last) = last_theme.replace(*theme) else {
+        return;
+    };
+
+    if let Some(mut clear_color) = clear_color {
+        if let Some(background) = last.background_of(clear_color.0) {
+            clear_color.0 = theme.color(background);
+        }
+    }
+    for (themed, mut sprite) in &mut sprite_query {
+        sprite.color = theme.color(themed.0);
+    }
+    for (themed, mut background_color) in &mut node_query {
+        background_color.0 = theme.color(themed.0);
+    }
+    for (themed, mut text) in &mut text_query {
+        for section in &mut text.sections {
+            section.style.color = theme.color(themed.0);
+        }
+    }
+    for (themed, handle) in &color_material_query {
+        if let Some(material) = color_materials.get_mut(handle) {
+            material.color = theme.color(themed.0);
+        }
+    }
+    for (themed, handle) in &standard_material_query {
+        if let Some(material) = standard_materials.get_mut(handle) {
+            material.base_color = theme.color(themed.0);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    ops::RangeInclusive,
+};
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+};
+use serde::de::DeserializeOwned;
+
+/// The numbers that set a game's feel, read from `assets/tuning/<game id>.ron` and reloaded
+/// whenever the file is saved, except on wasm. The game reads them as a resource.
+pub trait Tuning: Asset + Resource + Clone + PartialEq + DeserializeOwned {
+    /// Under `assets`.
+    const PATH: &'static str;
+    /// The file as shipped, built in so the values are there from the first frame.
+    const BUILT_IN: &'static str;
+
+    /// Reports every value out of its range.
+    fn check(&self, check: &mut TuningCheck);
+
+    fn built_in() -> Self {
+        parse(Self::BUILT_IN).unwrap_or_else(|error| panic!("invalid {}: {error}", Self::PATH))
+    }
+}
+
+/// What is wrong with a tuning file.
+#[derive(Debug)]
+pub enum TuningError {
+    Io(std::io::Error),
+    Parse(ron::error::SpannedError),
+    OutOfRange(Vec<String>),
+}
+
+impl Display for TuningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TuningError::Io(error) => write!(f, "couldn't be read: {error}"),
+            TuningError::Parse(error) => write!(f, "couldn't be parsed at {error}"),
+            TuningError::OutOfRange(problems) => {
+                write!(f, "has values out of range: {}", problems.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for TuningError {}
+
+/// Collects the values of a `Tuning` that are out of range.
+#[derive(Default, Debug)]
+pub struct TuningCheck {
+    problems: Vec<String>,
+}
+
+impl TuningCheck {
+    pub fn range<V: PartialOrd + Display>(
+        &mut self,
+        field: &str,
+        value: V,
+        range: RangeInclusive<V>,
+    ) -> &mut Self {
+        if !range.contains(&value) {
+            self.problems.push(format!(
+                "`{field}` is {value}, but must be from {} to {}",
+                range.start(),
+                range.end(),
+            ));
+        }
+        self
+    }
+
+    /// For limits that depend on other values, e.g. a zone inside a wider one.
+    pub fn rule(&mut self, holds: bool, problem: &str) -> &mut Self {
+        if !holds {
+            self.problems.push(problem.to_string());
+        }
+        self
+    }
+}
+
+/// A tuning from the text of its file, checked.
+pub fn parse<T: Tuning>(source: &str) -> Result<T, TuningError> {
+    let tuning: T = ron::from_str(source).map_err(TuningError::Parse)?;
+    let mut check = TuningCheck::default();
+    tuning.check(&mut check);
+    if check.problems.is_empty() {
+        Ok(tuning)
+    } else {
+        Err(TuningError::OutOfRange(check.problems))
+    }
+}
+
+struct TuningLoader<T>(PhantomData<T>);
+
+impl<T: Tuning> AssetLoader for TuningLoader<T> {
+    type Asset = T;
+    type Settings = ();
+    type Error = TuningError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<T, TuningError> {
+        let mut source = String::new();
+        reader
+            .read_to_string(&mut source)
+            .await
+            .map_err(TuningError::Io)?;
+        parse(&source)
+    }
+
+    // Every game's tuning is RON, the asset type picks the loader
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+#[derive(Resource)]
+struct TuningHandle<T: Tuning>(Handle<T>);
+
+/// Keeps the `T` resource on the game's tuning file. A file that fails to load or check is
+/// logged and the values before it are kept.
+pub struct TuningPlugin<T>(PhantomData<T>);
+
+impl<T> Default for TuningPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Tuning> Plugin for TuningPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<T>()
+            .register_asset_loader(TuningLoader::<T>(PhantomData))
+            .insert_resource(T::built_in())
+            .add_systems(Startup, load_tuning::<T>)
+            .add_systems(PreUpdate, apply_tuning::<T>);
+    }
+}
+
+fn load_tuning<T: Tuning>(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(TuningHandle::<T>(asset_server.load(T::PATH)));
+}
+
+fn apply_tuning<T: Tuning>(
+    mut asset_events: EventReader<AssetEvent<T>>,
+    handle: Option<Res<TuningHandle<T>>>,
+    assets: Res<Assets<T>>,
+    mut tuning: ResMut<T>,
+) {
+    let Some(handle) = handle else {
+        return;
+    };
+
+    for event in asset_events.read() {
+        if !event.is_loaded_with_dependencies(&handle.0) && !event.is_modified(&handle.0) {
+            continue;
+        }
+        if let Some(loaded) = assets.get(&handle.0) {
+            info!("{} applied", T::PATH);
+            tuning.set_if_neq(loaded.clone());
+        }
+    }
+}
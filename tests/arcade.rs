@@ -0,0 +1,186 @@
+mod common;
+
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput, NativeKey},
+        ButtonState,
+    },
+    prelude::*,
+    window::PrimaryWindow,
+};
+use ittokun_bevy_games::{
+    arcade::{ArcadePlugin, GameButton, HubButton},
+    games::click_game::ClickGamePlugin,
+    input::{Action, InputMap},
+    locale::Locale,
+    menu::{Menu, MenuButton},
+    mini_game::{MiniGame, MiniGames},
+    play_area::PlayArea,
+    settings::{SettingsButton, SettingsScreen},
+    theme::Theme,
+    ActiveGame, AppState, CurrentGame,
+};
+
+fn tap(app: &mut App, key_code: KeyCode) {
+    common::press_key(app, key_code);
+    common::run_frames(app, 1);
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state: ButtonState::Released,
+        window: Entity::PLACEHOLDER,
+    });
+    common::run_frames(app, 2);
+}
+
+fn active_game(app: &App) -> ActiveGame {
+    *app.world().resource::<State<ActiveGame>>().get()
+}
+
+fn cameras(app: &mut App) -> usize {
+    app.world_mut()
+        .query_filtered::<(), With<Camera>>()
+        .iter(app.world())
+        .count()
+}
+
+fn window_size(app: &mut App) -> Vec2 {
+    app.world_mut()
+        .query_filtered::<&Window, With<PrimaryWindow>>()
+        .single(app.world())
+        .size()
+}
+
+#[test]
+fn the_hub_starts_a_game_and_takes_it_back() {
+    let mut app = common::headless_app(ArcadePlugin);
+    common::run_frames(&mut app, 2);
+    let hub_window_size = window_size(&mut app);
+    assert_eq!(active_game(&app), ActiveGame(None));
+    assert!(app.world().get_resource::<State<AppState>>().is_none());
+    let buttons = app.world().resource::<Menu<HubButton>>().buttons();
+    let mini_games = app.world().resource::<MiniGames>();
+    assert_eq!(buttons.len(), mini_games.iter().count() + 1);
+    assert_eq!(buttons.last(), Some(&HubButton::Settings));
+    // Named like the cards, in the `Locale`'s language
+    let locale = app.world().resource::<Locale>();
+    for button in buttons {
+        if let HubButton::Game(game) = button {
+            let info = mini_games.get(game.id).unwrap();
+            assert_eq!(locale.text(button.label()), locale.pick(info.name));
+        }
+    }
+
+    // Timing Game, then Click Game
+    tap(&mut app, KeyCode::ArrowRight);
+    tap(&mut app, KeyCode::Enter);
+    let info = ClickGamePlugin::INFO;
+    assert_eq!(active_game(&app), ActiveGame(Some(info.id)));
+    assert_eq!(app.world().resource::<CurrentGame>().id, info.id);
+    // Letterboxed into the window as it is
+    assert_eq!(window_size(&mut app), hub_window_size);
+    assert_eq!(app.world().resource::<PlayArea>().size(), info.window_size);
+    assert!(app.world().get_resource::<Menu<HubButton>>().is_none());
+    assert_eq!(cameras(&mut app), 1);
+    // The press that chose the game doesn't skip its title screen
+    assert_eq!(common::current_state(&app), AppState::MainMenu);
+
+    tap(&mut app, KeyCode::Space);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+    common::set_state(&mut app, AppState::GameOver);
+    common::run_frames(&mut app, 2);
+
+    // The result screen's Title button
+    common::set_state(&mut app, AppState::MainMenu);
+    common::run_frames(&mut app, 3);
+    assert_eq!(active_game(&app), ActiveGame(None));
+    assert_eq!(window_size(&mut app), hub_window_size);
+    assert_eq!(cameras(&mut app), 1);
+    let menu = app.world().resource::<Menu<HubButton>>();
+    assert_eq!(
+        menu.selected(),
+        Some(HubButton::Game(GameButton::from(&info)))
+    );
+}
+
+#[test]
+fn every_game_starts_from_the_hub_and_tears_down() {
+    let mut app = common::headless_app(ArcadePlugin);
+    common::run_frames(&mut app, 2);
+    let games: Vec<_> = app
+        .world()
+        .resource::<MiniGames>()
+        .iter()
+        .copied()
+        .collect();
+    assert_eq!(games.len(), 7);
+
+    for info in games {
+        app.world_mut()
+            .resource_mut::<NextState<ActiveGame>>()
+            .set(ActiveGame(Some(info.id)));
+        common::wait_for_loading(&mut app);
+        assert_eq!(
+            common::current_state(&app),
+            AppState::MainMenu,
+            "{}",
+            info.id
+        );
+        assert_eq!(
+            app.world().resource::<CurrentGame>().score_order,
+            info.score_order
+        );
+        assert_eq!(app.world().resource::<PlayArea>().size(), info.window_size);
+        assert!(app.world().contains_resource::<InputMap<Action>>());
+
+        app.world_mut()
+            .resource_mut::<NextState<ActiveGame>>()
+            .set(ActiveGame(None));
+        common::run_frames(&mut app, 2);
+        assert!(
+            app.world().get_resource::<CurrentGame>().is_none(),
+            "{}",
+            info.id
+        );
+        assert!(app.world().get_resource::<InputMap<Action>>().is_none());
+        assert_eq!(
+            app.world().resource::<Time<Fixed>>().timestep(),
+            Time::<Fixed>::default().timestep()
+        );
+        assert_eq!(
+            app.world().resource::<ClearColor>().0,
+            app.world().resource::<Theme>().background
+        );
+        assert_eq!(cameras(&mut app), 1);
+    }
+}
+
+#[test]
+fn the_hub_opens_the_settings_and_comes_back() {
+    let mut app = common::headless_app(ArcadePlugin);
+    common::run_frames(&mut app, 2);
+
+    // From the first card back around to Settings
+    tap(&mut app, KeyCode::ArrowLeft);
+    tap(&mut app, KeyCode::Enter);
+    assert_eq!(
+        *app.world().resource::<State<SettingsScreen>>().get(),
+        SettingsScreen::Open
+    );
+    assert!(app.world().get_resource::<Menu<HubButton>>().is_none());
+    assert_eq!(cameras(&mut app), 1);
+    // No game, so no key bindings
+    let buttons = app.world().resource::<Menu<SettingsButton>>().buttons();
+    assert!(!buttons.contains(&SettingsButton::KeyBindings));
+    assert!(buttons.contains(&SettingsButton::Language));
+
+    tap(&mut app, KeyCode::Escape);
+    assert_eq!(
+        *app.world().resource::<State<SettingsScreen>>().get(),
+        SettingsScreen::Closed
+    );
+    assert_eq!(active_game(&app), ActiveGame(None));
+    let menu = app.world().resource::<Menu<HubButton>>();
+    assert_eq!(menu.selected(), Some(HubButton::Settings));
+    assert_eq!(cameras(&mut app), 1);
+}
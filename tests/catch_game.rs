@@ -0,0 +1,192 @@
+mod common;
+
+use bevy::{
+    input::gamepad::{GamepadConnection, GamepadConnectionEvent, GamepadEvent, GamepadInfo},
+    prelude::*,
+};
+use ittokun_bevy_games::{
+    collision::Collider,
+    games::catch_game::CatchGamePlugin,
+    high_scores::HighScores,
+    mini_game::MiniGame,
+    replay::{ReplayPlayer, ReplayRecorder},
+    result_screen::GameFinished,
+    rng::GameRng,
+    AppState,
+};
+
+// Covers the 30 second time limit with room to spare
+const FULL_RUN_FRAMES: usize = 1900;
+
+#[test]
+fn runs_through_every_state() {
+    // Runs back and forth until the time limit ends the run
+    common::smoke_test(
+        common::headless_app(CatchGamePlugin),
+        AppState::GameOver,
+        FULL_RUN_FRAMES,
+        |app, frame| match frame % 120 {
+            0 => {
+                common::release_key(app, KeyCode::ArrowLeft);
+                common::press_key(app, KeyCode::ArrowRight);
+            }
+            60 => {
+                common::release_key(app, KeyCode::ArrowRight);
+                common::press_key(app, KeyCode::ArrowLeft);
+            }
+            _ => {}
+        },
+    );
+}
+
+#[test]
+fn connecting_a_gamepad_pauses_until_a_key_is_pressed() {
+    let mut app = common::headless_app(CatchGamePlugin);
+
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+
+    app.world_mut()
+        .send_event(GamepadEvent::Connection(GamepadConnectionEvent::new(
+            Gamepad::new(0),
+            GamepadConnection::Connected(GamepadInfo {
+                name: "catch_game_test".to_string(),
+            }),
+        )));
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::Paused);
+
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+}
+
+fn collider_positions(app: &mut App) -> Vec<Vec3> {
+    let mut query = app
+        .world_mut()
+        .query_filtered::<&Transform, With<Collider>>();
+    query
+        .iter(app.world())
+        .map(|transform| transform.translation)
+        .collect()
+}
+
+#[test]
+fn the_pause_menu_freezes_the_run_and_restarts_it() {
+    let mut app = common::headless_app(CatchGamePlugin);
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 120);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+
+    common::press_key(&mut app, KeyCode::Escape);
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::Paused);
+    assert!(app.world().resource::<Time<Virtual>>().is_paused());
+    let paused_positions = collider_positions(&mut app);
+    assert!(paused_positions.len() > 1);
+    common::run_frames(&mut app, 60);
+    assert_eq!(collider_positions(&mut app), paused_positions);
+
+    // Esc again resumes where the run stopped
+    common::press_key(&mut app, KeyCode::Escape);
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+    assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+    assert_eq!(collider_positions(&mut app), paused_positions);
+    common::run_frames(&mut app, 2);
+    assert_ne!(collider_positions(&mut app), paused_positions);
+
+    // Resume, Restart, ...
+    common::press_key(&mut app, KeyCode::Escape);
+    common::run_frames(&mut app, 2);
+    common::press_key(&mut app, KeyCode::ArrowDown);
+    common::run_frames(&mut app, 1);
+    common::press_key(&mut app, KeyCode::Enter);
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+    assert_eq!(collider_positions(&mut app).len(), 1);
+}
+
+fn play_seeded(seed: u64) -> Vec<Vec3> {
+    let mut app = common::headless_app(CatchGamePlugin);
+    app.insert_resource(GameRng::new(seed));
+
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 300);
+
+    collider_positions(&mut app)
+}
+
+#[test]
+fn same_seed_spawns_the_same_obstacles() {
+    let obstacles = play_seeded(7);
+
+    assert!(obstacles.len() > 1);
+    assert_eq!(play_seeded(7), obstacles);
+    assert_ne!(play_seeded(8), obstacles);
+}
+
+#[derive(Resource, Default)]
+struct SubmittedScores(Vec<f32>);
+
+fn collect_scores(
+    mut finished_events: EventReader<GameFinished>,
+    mut scores: ResMut<SubmittedScores>,
+) {
+    scores
+        .0
+        .extend(finished_events.read().filter_map(|event| event.score));
+}
+
+#[test]
+fn replaying_a_run_scores_the_same() {
+    let mut app = common::headless_app(CatchGamePlugin);
+    app.init_resource::<SubmittedScores>()
+        .add_systems(PostUpdate, collect_scores);
+
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 2);
+    // Sweep left and right so the score depends on the input. The result screen reads keys too,
+    // so stop pressing before the time limit
+    for key_code in [KeyCode::ArrowLeft, KeyCode::ArrowRight]
+        .into_iter()
+        .cycle()
+        .take(17)
+    {
+        common::press_key(&mut app, key_code);
+        common::run_frames(&mut app, 50);
+        common::release_key(&mut app, key_code);
+        common::run_frames(&mut app, 50);
+    }
+    common::run_frames(&mut app, FULL_RUN_FRAMES - 1700);
+    assert_eq!(common::current_state(&app), AppState::GameOver);
+    let recorded_scores = app.world().resource::<SubmittedScores>().0.clone();
+    let replay = app
+        .world()
+        .resource::<ReplayRecorder>()
+        .last
+        .clone()
+        .unwrap();
+    assert_eq!(recorded_scores.len(), 1);
+    // A run that caught nothing would replay the same score with any input
+    assert_ne!(recorded_scores[0], 0.0);
+
+    let mut replay_app = common::headless_app(CatchGamePlugin);
+    replay_app
+        .init_resource::<SubmittedScores>()
+        .add_systems(PostUpdate, collect_scores)
+        .insert_resource(GameRng::new(replay.seed))
+        .insert_resource(ReplayPlayer::new(replay))
+        .insert_resource(HighScores::default());
+
+    common::run_frames(&mut replay_app, FULL_RUN_FRAMES);
+    assert_eq!(common::current_state(&replay_app), AppState::GameOver);
+    assert_eq!(
+        replay_app.world().resource::<SubmittedScores>().0,
+        recorded_scores
+    );
+    // Only the live run makes the table
+    let high_scores = replay_app.world().resource::<HighScores>();
+    assert!(high_scores.entries(CatchGamePlugin::INFO.id).is_empty());
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use bevy::{
+    input::touch::{TouchInput, TouchPhase},
+    prelude::*,
+};
+use ittokun_bevy_games::{
+    games::click_game::ClickGamePlugin,
+    play_area::PlayArea,
+    theme::{ThemeColor, Themed},
+    AppState,
+};
+
+fn touch(app: &mut App, id: u64, phase: TouchPhase, position: Vec2) {
+    app.world_mut().send_event(TouchInput {
+        phase,
+        position,
+        window: Entity::PLACEHOLDER,
+        force: None,
+        id,
+    });
+}
+
+#[test]
+fn runs_through_every_state() {
+    // Taps a ball every other frame until none are left
+    common::smoke_test(
+        common::headless_app(ClickGamePlugin),
+        AppState::GameClear,
+        600,
+        |app, frame| {
+            if frame % 2 == 1 {
+                touch(app, 0, TouchPhase::Ended, Vec2::ZERO);
+                return;
+            }
+            let play_area = *app.world().resource::<PlayArea>();
+            let ball = app
+                .world_mut()
+                .query::<(&Transform, &Themed)>()
+                .iter(app.world())
+                .find(|(_, themed)| themed.0 == ThemeColor::Enemy)
+                .map(|(transform, _)| transform.translation.truncate());
+            if let Some(ball) = ball {
+                touch(app, 0, TouchPhase::Started, play_area.world_to_window(ball));
+            }
+        },
+    );
+}
@@ -0,0 +1,170 @@
+// Each test crate that includes this module only uses some of the helpers
+#![allow(dead_code)]
+
+use std::{thread, time::Duration};
+
+use bevy::{
+    audio::AudioLoader,
+    gltf::GltfPlugin,
+    input::{
+        keyboard::{Key, KeyboardInput, NativeKey},
+        ButtonState, InputPlugin,
+    },
+    prelude::*,
+    state::app::StatesPlugin,
+    text::FontLoader,
+    time::TimeUpdateStrategy,
+    window::PrimaryWindow,
+};
+use ittokun_bevy_games::{
+    loading::LoadingProgress,
+    theme::{ThemeColor, Themed},
+    AppState, MiniGamePlugin,
+};
+
+const FRAME_TIME: f32 = 1.0 / 60.0;
+
+// Builds an app without a renderer or audio device so a game can be driven frame by frame
+pub fn headless_app(game: impl Plugin) -> App {
+    // Keep high scores and other saved data out of the real data directory
+    std::env::set_var("XDG_DATA_HOME", env!("CARGO_TARGET_TMPDIR"));
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        StatesPlugin,
+        AssetPlugin::default(),
+        InputPlugin,
+    ))
+    .init_asset::<Mesh>()
+    .init_asset::<Image>()
+    .init_asset::<Font>()
+    .init_asset::<Scene>()
+    .init_asset::<AudioSource>()
+    .init_asset::<ColorMaterial>()
+    .init_asset::<StandardMaterial>()
+    // The loaders need no renderer or audio device, so the assets load as in the game
+    .init_asset_loader::<FontLoader>()
+    .init_asset_loader::<AudioLoader>()
+    .add_plugins(GltfPlugin::default())
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        FRAME_TIME,
+    )))
+    .add_plugins(MiniGamePlugin)
+    .add_plugins(game);
+
+    app.world_mut().spawn((Window::default(), PrimaryWindow));
+    app.finish();
+    app.cleanup();
+    wait_for_loading(&mut app);
+    app
+}
+
+// Assets load off the main thread, so every test starts once they have, or once one has failed
+pub fn wait_for_loading(app: &mut App) {
+    for _ in 0..500 {
+        app.update();
+        let progress = app.world().resource::<LoadingProgress>();
+        let loading = app
+            .world()
+            .get_resource::<State<AppState>>()
+            .is_some_and(|state| *state.get() == AppState::Loading);
+        if !progress.failed.is_empty() || (progress.is_done() && !loading) {
+            // The title screen or the list of what failed
+            app.update();
+            return;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    panic!("assets took over 5 seconds to load");
+}
+
+pub fn run_frames(app: &mut App, frames: usize) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+pub fn press_key(app: &mut App, key_code: KeyCode) {
+    let window = app
+        .world_mut()
+        .query_filtered::<Entity, With<PrimaryWindow>>()
+        .single(app.world());
+
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state: ButtonState::Pressed,
+        window,
+    });
+}
+
+pub fn release_key(app: &mut App, key_code: KeyCode) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state: ButtonState::Released,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+pub fn tap_key(app: &mut App, key_code: KeyCode) {
+    press_key(app, key_code);
+    app.update();
+    release_key(app, key_code);
+    app.update();
+}
+
+// Every game themes its player, so tests can follow it without reaching into the game
+pub fn player_position(app: &mut App) -> Option<Vec3> {
+    app.world_mut()
+        .query::<(&Transform, &Themed)>()
+        .iter(app.world())
+        .find(|(_, themed)| themed.0 == ThemeColor::Player)
+        .map(|(transform, _)| transform.translation)
+}
+
+pub fn current_state(app: &App) -> AppState {
+    *app.world().resource::<State<AppState>>().get()
+}
+
+pub fn set_state(app: &mut App, state: AppState) {
+    app.world_mut()
+        .resource_mut::<NextState<AppState>>()
+        .set(state);
+}
+
+// From the title screen into a run, through the pause menu and back
+pub fn start_run(app: &mut App) {
+    assert_eq!(current_state(app), AppState::MainMenu);
+    tap_key(app, KeyCode::Space);
+    assert_eq!(current_state(app), AppState::InGame);
+
+    tap_key(app, KeyCode::Escape);
+    assert_eq!(current_state(app), AppState::Paused);
+    tap_key(app, KeyCode::Escape);
+    assert_eq!(current_state(app), AppState::InGame);
+}
+
+// Starts a run and hands every frame to `play` until the game itself ends it in `end_state`,
+// within `max_frames`
+pub fn smoke_test(
+    mut app: App,
+    end_state: AppState,
+    max_frames: usize,
+    mut play: impl FnMut(&mut App, usize),
+) {
+    start_run(&mut app);
+
+    for frame in 0..max_frames {
+        if current_state(&app) != AppState::InGame {
+            break;
+        }
+        play(&mut app, frame);
+        app.update();
+    }
+    // The result screen follows the frame after
+    app.update();
+    assert_eq!(current_state(&app), end_state);
+}
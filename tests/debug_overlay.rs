@@ -0,0 +1,75 @@
+mod common;
+
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput, NativeKey},
+        ButtonState,
+    },
+    prelude::*,
+};
+use ittokun_bevy_games::{
+    debug_overlay::{DebugOverlay, DebugOverlayText},
+    games::shooting_2d::Shooting2dPlugin,
+    AppState,
+};
+
+fn tap(app: &mut App, key_code: KeyCode) {
+    common::press_key(app, key_code);
+    common::run_frames(app, 1);
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state: ButtonState::Released,
+        window: Entity::PLACEHOLDER,
+    });
+    common::run_frames(app, 1);
+}
+
+fn overlay_text(app: &mut App) -> Option<String> {
+    app.world_mut()
+        .query_filtered::<&Text, With<DebugOverlayText>>()
+        .get_single(app.world())
+        .ok()
+        .map(|text| text.sections[0].value.clone())
+}
+
+#[test]
+fn f3_toggles_the_overlay_without_starting_the_game() {
+    let mut app = common::headless_app(Shooting2dPlugin);
+    common::run_frames(&mut app, 2);
+    assert_eq!(overlay_text(&mut app), None);
+
+    tap(&mut app, KeyCode::F3);
+    assert_eq!(common::current_state(&app), AppState::MainMenu);
+    assert!(app.world().resource::<DebugOverlay>().visible);
+    let text = overlay_text(&mut app).unwrap();
+    assert!(text.contains("State MainMenu"), "{text}");
+    assert!(text.contains("Bullet 0"), "{text}");
+
+    tap(&mut app, KeyCode::F3);
+    assert_eq!(overlay_text(&mut app), None);
+}
+
+#[test]
+fn the_overlay_counts_entities_and_outlives_runs() {
+    let mut app = common::headless_app(Shooting2dPlugin);
+    common::run_frames(&mut app, 2);
+    tap(&mut app, KeyCode::F3);
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 30);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+
+    let bullets = app
+        .world()
+        .resource::<DebugOverlay>()
+        .count("Bullet")
+        .unwrap();
+    assert!(bullets > 0);
+    let text = overlay_text(&mut app).unwrap();
+    assert!(text.contains("State InGame"), "{text}");
+    assert!(text.contains(&format!("Bullet {bullets}")), "{text}");
+
+    common::set_state(&mut app, AppState::GameOver);
+    common::run_frames(&mut app, 2);
+    assert!(overlay_text(&mut app).unwrap().contains("State GameOver"));
+}
@@ -0,0 +1,24 @@
+mod common;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+use ittokun_bevy_games::{games::doodle_jump::DoodleJumpPlugin, AppState};
+
+// Only its first stage is laid out so far, so no run ends yet
+#[test]
+fn esc_pauses_a_run_instead_of_closing_the_window() {
+    let mut app = common::headless_app(DoodleJumpPlugin);
+    app.world_mut()
+        .query_filtered::<&mut Window, With<PrimaryWindow>>()
+        .single_mut(app.world_mut())
+        .focused = true;
+
+    common::start_run(&mut app);
+    common::run_frames(&mut app, 120);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+    let windows = app
+        .world_mut()
+        .query_filtered::<(), With<PrimaryWindow>>()
+        .iter(app.world())
+        .count();
+    assert_eq!(windows, 1);
+}
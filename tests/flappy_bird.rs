@@ -0,0 +1,79 @@
+mod common;
+
+use bevy::{
+    input::touch::{TouchInput, TouchPhase},
+    prelude::*,
+};
+use ittokun_bevy_games::{
+    games::flappy_bird::{FlappyBirdPlugin, FlappyBirdTuning},
+    play_area::PlayArea,
+    theme::{ThemeColor, Themed},
+    AppState,
+};
+
+#[test]
+fn runs_through_every_state() {
+    // Keeps flapping up into the top obstacles until its lives run out
+    let mut flapped = false;
+    common::smoke_test(
+        common::headless_app(FlappyBirdPlugin),
+        AppState::GameOver,
+        1800,
+        |app, _| {
+            if flapped {
+                common::release_key(app, KeyCode::Space);
+                flapped = false;
+            } else if common::player_position(app).is_some_and(|position| position.y < 150.0) {
+                common::press_key(app, KeyCode::Space);
+                flapped = true;
+            }
+        },
+    );
+}
+
+#[test]
+fn a_tap_dismisses_the_title() {
+    let mut app = common::headless_app(FlappyBirdPlugin);
+    common::run_frames(&mut app, 2);
+
+    app.world_mut().send_event(TouchInput {
+        phase: TouchPhase::Started,
+        position: Vec2::new(100.0, 100.0),
+        window: Entity::PLACEHOLDER,
+        force: None,
+        id: 0,
+    });
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+}
+
+#[test]
+fn obstacles_reach_from_the_edges_of_the_play_area_to_the_gap() {
+    let mut app = common::headless_app(FlappyBirdPlugin);
+    common::wait_for_loading(&mut app);
+    let play_area = PlayArea::new(Vec2::new(700.0, 400.0));
+    app.insert_resource(play_area);
+    common::start_run(&mut app);
+    let space = app.world().resource::<FlappyBirdTuning>().obstacle_space;
+
+    let mut obstacles = Vec::new();
+    for _ in 0..300 {
+        app.update();
+        let mut query = app.world_mut().query::<(&Themed, &Transform)>();
+        obstacles = query
+            .iter(app.world())
+            .filter(|(themed, _)| matches!(themed.0, ThemeColor::Bad))
+            .map(|(_, transform)| *transform)
+            .collect();
+        if !obstacles.is_empty() {
+            break;
+        }
+    }
+
+    assert_eq!(obstacles.len(), 2);
+    for obstacle in obstacles {
+        assert_eq!(obstacle.scale.y, (play_area.size().y - space) / 2.0);
+        let edge = obstacle.translation.y.abs() + obstacle.scale.y / 2.0;
+        assert_eq!(edge, play_area.top());
+    }
+}
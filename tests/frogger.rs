@@ -0,0 +1,67 @@
+mod common;
+
+use bevy::prelude::*;
+use ittokun_bevy_games::{
+    games::frogger::{FroggerPlugin, FroggerTuning},
+    loading::{GameModels, LoadingProgress, UiFonts},
+    AppState,
+};
+
+#[test]
+fn runs_through_every_state() {
+    let mut app = common::headless_app(FroggerPlugin);
+    // A shorter run, ended by its time limit all the same
+    app.world_mut()
+        .resource_mut::<FroggerTuning>()
+        .game_time_limit = 5.0;
+    // Hops forward twice a second
+    common::smoke_test(app, AppState::GameOver, 600, |app, frame| {
+        match frame % 30 {
+            0 => common::press_key(app, KeyCode::ArrowUp),
+            1 => common::release_key(app, KeyCode::ArrowUp),
+            _ => {}
+        }
+    });
+}
+
+#[test]
+fn a_placeholder_stands_in_for_the_missing_player_model() {
+    let app = common::headless_app(FroggerPlugin);
+    assert_eq!(common::current_state(&app), AppState::MainMenu);
+    assert!(app.world().resource::<LoadingProgress>().failed.is_empty());
+
+    let models = app.world().resource::<GameModels>();
+    assert!(models.is_placeholder("models/Frogger/gekota.glb#Scene0"));
+    assert!(!models.is_placeholder("models/Frogger/tile.glb#Scene0"));
+}
+
+#[test]
+fn an_asset_without_a_fallback_stops_on_the_error_screen() {
+    let mut app = common::headless_app(FroggerPlugin);
+    let missing = app
+        .world()
+        .resource::<AssetServer>()
+        .load("fonts/Missing.ttf");
+    app.world_mut().resource_mut::<UiFonts>().mono = missing;
+    common::set_state(&mut app, AppState::Loading);
+    common::wait_for_loading(&mut app);
+    assert_eq!(common::current_state(&app), AppState::Loading);
+    let progress = app.world().resource::<LoadingProgress>();
+    let failed: Vec<_> = progress
+        .failed
+        .iter()
+        .map(|(path, _)| path.as_str())
+        .collect();
+    assert_eq!(failed, ["fonts/Missing.ttf"]);
+
+    let texts: Vec<String> = app
+        .world_mut()
+        .query::<&Text>()
+        .iter(app.world())
+        .map(|text| text.sections[0].value.clone())
+        .collect();
+    assert!(
+        texts.iter().any(|text| text.contains("fonts/Missing.ttf")),
+        "{texts:?}"
+    );
+}
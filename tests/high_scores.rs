@@ -0,0 +1,99 @@
+mod common;
+
+use bevy::prelude::*;
+use ittokun_bevy_games::{
+    games::catch_game::CatchGamePlugin,
+    high_scores::{HighScoreEntry, HighScores, ScoreOrder, Stat, MAX_HIGH_SCORES},
+    locale::Locale,
+    mini_game::MiniGame,
+    AppState,
+};
+
+fn entry(score: f32) -> HighScoreEntry {
+    HighScoreEntry {
+        name: "tester".to_string(),
+        score,
+        date: "2024-01-01".to_string(),
+        extras: Vec::new(),
+    }
+}
+
+fn scores(high_scores: &HighScores, game: &str) -> Vec<f32> {
+    high_scores
+        .entries(game)
+        .iter()
+        .map(|entry| entry.score)
+        .collect()
+}
+
+#[test]
+fn keeps_the_best_ten_in_order() {
+    let mut high_scores = HighScores::default();
+
+    for score in 0..15 {
+        high_scores.insert("game", ScoreOrder::HigherIsBetter, entry(score as f32));
+    }
+
+    let expected: Vec<f32> = (5..15).rev().map(|score| score as f32).collect();
+    assert_eq!(scores(&high_scores, "game"), expected);
+    assert_eq!(
+        high_scores.insert("game", ScoreOrder::HigherIsBetter, entry(5.0)),
+        None
+    );
+    assert_eq!(
+        high_scores.insert("game", ScoreOrder::HigherIsBetter, entry(100.0)),
+        Some(0)
+    );
+    assert_eq!(high_scores.entries("game").len(), MAX_HIGH_SCORES);
+}
+
+#[test]
+fn lower_is_better_ranks_fastest_first() {
+    let mut high_scores = HighScores::default();
+
+    high_scores.insert("race", ScoreOrder::LowerIsBetter, entry(12.5));
+    high_scores.insert("race", ScoreOrder::LowerIsBetter, entry(9.0));
+    let rank = high_scores.insert("race", ScoreOrder::LowerIsBetter, entry(10.0));
+
+    assert_eq!(rank, Some(1));
+    assert!(high_scores.is_latest("race", 1));
+    assert_eq!(scores(&high_scores, "race"), vec![9.0, 10.0, 12.5]);
+    assert!(high_scores.entries("other").is_empty());
+}
+
+#[test]
+fn stats_are_kept_by_id_and_shown_in_the_language() {
+    let mut with_time = entry(10.0);
+    with_time.extras.push((Stat::Time, "1.5s".to_string()));
+    let json = serde_json::to_string(&with_time).unwrap();
+    assert!(json.contains(r#"["time","1.5s"]"#), "{json}");
+    assert_eq!(
+        serde_json::from_str::<HighScoreEntry>(&json).unwrap(),
+        with_time
+    );
+
+    let mut app = common::headless_app(CatchGamePlugin);
+    let mut high_scores = HighScores::default();
+    high_scores.insert(
+        CatchGamePlugin::INFO.id,
+        ScoreOrder::HigherIsBetter,
+        with_time,
+    );
+    app.insert_resource(high_scores);
+    common::set_state(&mut app, AppState::GameOver);
+    common::run_frames(&mut app, 2);
+
+    let time = app
+        .world()
+        .resource::<Locale>()
+        .get(Stat::Time.label())
+        .to_string();
+    assert_ne!(time, Stat::Time.label());
+    let shown = app
+        .world_mut()
+        .query::<&Text>()
+        .iter(app.world())
+        .flat_map(|text| text.sections.iter().map(|section| section.value.clone()))
+        .any(|value| value.contains(&format!(" {time}:1.5s")));
+    assert!(shown);
+}
@@ -0,0 +1,170 @@
+use std::time::Duration;
+
+use bevy::{
+    input::{
+        gamepad::{
+            GamepadAxisChangedEvent, GamepadConnection, GamepadConnectionEvent, GamepadEvent,
+            GamepadInfo,
+        },
+        keyboard::{Key, KeyboardInput, NativeKey},
+        ButtonState, InputPlugin,
+    },
+    prelude::*,
+    time::TimeUpdateStrategy,
+};
+use ittokun_bevy_games::input::{
+    action_axis, Action, AxisDirection, InputBinding, InputMap, InputMapPlugin,
+};
+
+fn send_key(app: &mut App, key_code: KeyCode, state: ButtonState) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+fn move_stick(app: &mut App, gamepad: Gamepad, value: f32) {
+    app.world_mut()
+        .send_event(GamepadEvent::Axis(GamepadAxisChangedEvent::new(
+            gamepad,
+            GamepadAxisType::LeftStickX,
+            value,
+        )));
+}
+
+#[test]
+fn actions_follow_their_bindings() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin))
+        .add_plugins(InputMapPlugin::<Action>::default())
+        .insert_resource(InputMap::new("input_test").bind(Action::Jump, KeyCode::Space));
+
+    app.world_mut()
+        .resource_mut::<InputMap<Action>>()
+        .rebind(Action::Jump, vec![InputBinding::Key(KeyCode::KeyW)]);
+
+    send_key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    app.update();
+    assert!(!app
+        .world()
+        .resource::<ButtonInput<Action>>()
+        .pressed(Action::Jump));
+
+    send_key(&mut app, KeyCode::KeyW, ButtonState::Pressed);
+    app.update();
+    let actions = app.world().resource::<ButtonInput<Action>>();
+    assert!(actions.just_pressed(Action::Jump));
+
+    app.update();
+    let actions = app.world().resource::<ButtonInput<Action>>();
+    assert!(actions.pressed(Action::Jump));
+    assert!(!actions.just_pressed(Action::Jump));
+
+    send_key(&mut app, KeyCode::KeyW, ButtonState::Released);
+    app.update();
+    assert!(app
+        .world()
+        .resource::<ButtonInput<Action>>()
+        .just_released(Action::Jump));
+}
+
+#[test]
+fn sticks_drive_action_axes() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin))
+        .add_plugins(InputMapPlugin::<Action>::default())
+        .insert_resource(
+            InputMap::new("input_test")
+                .bind(
+                    Action::MoveLeft,
+                    (GamepadAxisType::LeftStickX, AxisDirection::Negative),
+                )
+                .bind(
+                    Action::MoveRight,
+                    (GamepadAxisType::LeftStickX, AxisDirection::Positive),
+                ),
+        );
+
+    let gamepad = Gamepad::new(0);
+    app.world_mut()
+        .send_event(GamepadEvent::Connection(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected(GamepadInfo {
+                name: "input_test".to_string(),
+            }),
+        )));
+    app.update();
+
+    // A light push moves the axis without pressing the action
+    move_stick(&mut app, gamepad, 0.3);
+    app.update();
+    let world = app.world();
+    let axis = action_axis(
+        world.resource::<Axis<Action>>(),
+        Action::MoveLeft,
+        Action::MoveRight,
+    );
+    assert!((axis - 0.3).abs() < 1e-6);
+    assert!(!world
+        .resource::<ButtonInput<Action>>()
+        .pressed(Action::MoveRight));
+
+    move_stick(&mut app, gamepad, -0.8);
+    app.update();
+    let world = app.world();
+    let axis = action_axis(
+        world.resource::<Axis<Action>>(),
+        Action::MoveLeft,
+        Action::MoveRight,
+    );
+    assert!((axis + 0.8).abs() < 1e-6);
+    let actions = world.resource::<ButtonInput<Action>>();
+    assert!(actions.just_pressed(Action::MoveLeft));
+    assert!(!actions.pressed(Action::MoveRight));
+}
+
+#[derive(Resource, Default)]
+struct JumpTicks(Vec<bool>);
+
+fn record_jump(actions: Res<ButtonInput<Action>>, mut jump_ticks: ResMut<JumpTicks>) {
+    jump_ticks.0.push(actions.just_pressed(Action::Jump));
+}
+
+fn fixed_jump_ticks(frame_time: f64) -> Vec<bool> {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin))
+        .add_plugins(InputMapPlugin::<Action>::default())
+        .insert_resource(InputMap::new("input_test").bind(Action::Jump, KeyCode::Space))
+        .insert_resource(Time::<Fixed>::from_seconds(1.0 / 60.0))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+            frame_time,
+        )))
+        .init_resource::<JumpTicks>()
+        .add_systems(FixedUpdate, record_jump);
+
+    app.update();
+    send_key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    app.update();
+    send_key(&mut app, KeyCode::Space, ButtonState::Released);
+    for _ in 0..10 {
+        app.update();
+    }
+    app.world().resource::<JumpTicks>().0.clone()
+}
+
+#[test]
+fn each_press_reaches_exactly_one_fixed_tick() {
+    // Two ticks a frame, and a frame too short for any tick
+    for frame_time in [1.0 / 30.0, 1.0 / 144.0] {
+        let jump_ticks = fixed_jump_ticks(frame_time);
+        assert_eq!(
+            jump_ticks
+                .iter()
+                .filter(|just_pressed| **just_pressed)
+                .count(),
+            1
+        );
+    }
+}
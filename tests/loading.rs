@@ -0,0 +1,73 @@
+mod common;
+
+use std::path::Path;
+
+use bevy::{prelude::*, utils::HashSet};
+use ittokun_bevy_games::{
+    arcade::ArcadePlugin,
+    games::catch_game::CatchGamePlugin,
+    loading::{missing_assets, referenced_assets, Fallback, LoadingProgress, UiFonts},
+    locale::Locale,
+    mini_game::MiniGames,
+    music::GameMusic,
+    sfx::SfxEvent,
+    AppState,
+};
+
+#[test]
+fn the_title_screen_waits_for_every_font_and_sound() {
+    let app = common::headless_app(CatchGamePlugin);
+    assert_eq!(common::current_state(&app), AppState::MainMenu);
+
+    let progress = app.world().resource::<LoadingProgress>();
+    assert!(progress.failed.is_empty(), "{:?}", progress.failed);
+    // Events that share a sound load it once
+    let sounds: HashSet<_> = SfxEvent::ALL
+        .iter()
+        .map(|event| event.sound().path)
+        .collect();
+    assert_eq!(progress.total, 2 + sounds.len());
+    assert!(progress.is_done());
+
+    let fonts = app.world().resource::<UiFonts>();
+    let loaded = app.world().resource::<Assets<Font>>();
+    assert!(loaded.contains(&fonts.text) && loaded.contains(&fonts.mono));
+    assert!(loaded.contains(&app.world().resource::<Locale>().font()));
+}
+
+#[test]
+fn progress_counts_up_to_done() {
+    let mut progress = LoadingProgress {
+        loaded: 1,
+        total: 4,
+        failed: Vec::new(),
+    };
+    assert_eq!(progress.fraction(), 0.25);
+    assert!(!progress.is_done());
+
+    progress.loaded = 4;
+    assert_eq!(progress.fraction(), 1.0);
+    assert!(progress.is_done());
+    assert_eq!(LoadingProgress::default().fraction(), 1.0);
+}
+
+#[test]
+fn every_referenced_asset_is_there_or_falls_back() {
+    let app = common::headless_app(ArcadePlugin);
+    let mini_games = app.world().resource::<MiniGames>();
+    let referenced = referenced_assets(mini_games);
+    assert!(referenced
+        .iter()
+        .any(|asset| asset.path == "models/Frogger/tile.glb"));
+    assert!(referenced
+        .iter()
+        .any(|asset| asset.path == GameMusic::DEFAULT.play));
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets");
+    let missing = missing_assets(&root, mini_games);
+    let without_fallback: Vec<_> = missing
+        .iter()
+        .filter(|asset| asset.fallback == Fallback::None)
+        .collect();
+    assert!(without_fallback.is_empty(), "{without_fallback:?}");
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use ittokun_bevy_games::{
+    games::click_game::ClickGamePlugin,
+    loading::UiFonts,
+    locale::{Language, Locale, LocalizedText},
+    settings::Settings,
+    PressAnyKey,
+};
+
+fn press_any_key_text(app: &mut App) -> String {
+    app.world_mut()
+        .query_filtered::<&Text, With<PressAnyKey>>()
+        .single(app.world())
+        .sections[0]
+        .value
+        .clone()
+}
+
+#[test]
+fn every_catalog_has_the_same_keys() {
+    let parse = |catalog: &str| -> BTreeMap<String, String> { ron::from_str(catalog).unwrap() };
+    let en = parse(include_str!("../assets/locale/en.ron"));
+    let ja = parse(include_str!("../assets/locale/ja.ron"));
+
+    assert_eq!(en.keys().collect::<Vec<_>>(), ja.keys().collect::<Vec<_>>());
+    assert!(en
+        .values()
+        .chain(ja.values())
+        .all(|value| !value.is_empty()));
+}
+
+#[test]
+#[ignore = "needs assets/fonts/NotoSansJP-Bold.ttf"]
+fn japanese_is_shown_in_its_own_font() {
+    assert_eq!(Settings::default().language, Language::Japanese);
+    let mut app = common::headless_app(ClickGamePlugin);
+    app.world_mut().resource_mut::<Settings>().language = Language::Japanese;
+    common::run_frames(&mut app, 2);
+
+    let locale = app.world().resource::<Locale>();
+    assert_eq!(locale.language(), Language::Japanese);
+    let japanese = app.world().resource::<UiFonts>().japanese.clone();
+    assert_eq!(locale.font(), japanese);
+    assert_ne!(locale.font(), app.world().resource::<UiFonts>().text);
+    assert!(app.world().resource::<Assets<Font>>().contains(&japanese));
+
+    let text = app
+        .world_mut()
+        .query_filtered::<&Text, With<PressAnyKey>>()
+        .single(app.world())
+        .clone();
+    assert_eq!(text.sections[0].value, "何かキーを押してください ...");
+    assert_eq!(text.sections[0].style.font, japanese);
+}
+
+// Only while the Japanese font is not in `assets/fonts`
+#[test]
+fn a_language_without_its_font_is_shown_in_english() {
+    let mut app = common::headless_app(ClickGamePlugin);
+    app.world_mut().resource_mut::<Settings>().language = Language::Japanese;
+    common::run_frames(&mut app, 3);
+
+    assert_eq!(
+        app.world().resource::<Locale>().language(),
+        Language::English
+    );
+    assert_eq!(
+        app.world().resource::<Settings>().language,
+        Language::Japanese
+    );
+    assert_eq!(press_any_key_text(&mut app), "Press Any Key ...");
+}
+
+#[test]
+fn localized_texts_follow_the_locale() {
+    let mut app = common::headless_app(ClickGamePlugin);
+    common::run_frames(&mut app, 2);
+
+    let entity = app
+        .world_mut()
+        .spawn((
+            TextBundle::from_sections([
+                TextSection::new("", TextStyle::default()),
+                TextSection::new("42", TextStyle::default()),
+                TextSection::new("", TextStyle::default()),
+            ]),
+            LocalizedText::new("hud_score").with_section(2, "no_such_key"),
+        ))
+        .id();
+    common::run_frames(&mut app, 1);
+
+    let text = app.world().get::<Text>(entity).unwrap();
+    let values: Vec<_> = text
+        .sections
+        .iter()
+        .map(|section| section.value.as_str())
+        .collect();
+    assert_eq!(values, ["Score: ", "42", "no_such_key"]);
+    assert_eq!(
+        text.sections[0].style.font,
+        app.world().resource::<Locale>().font()
+    );
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use bevy::prelude::*;
+use ittokun_bevy_games::{
+    arcade::ArcadePlugin,
+    games::catch_game::CatchGamePlugin,
+    mini_game::MiniGame,
+    music::{GameMusic, Music, MusicPlayer},
+    sfx::SfxEvent,
+    ActiveGame, AppState,
+};
+
+fn music(app: &mut App) -> Vec<(Handle<AudioSource>, f32, bool)> {
+    app.world_mut()
+        .query::<(&Handle<AudioSource>, &Music)>()
+        .iter(app.world())
+        .map(|(track, music)| (track.clone(), music.level(), music.fading_out()))
+        .collect()
+}
+
+#[test]
+fn music_crossfades_between_states() {
+    let mut app = common::headless_app(CatchGamePlugin);
+    // No music ships, so sound effects stand in for the game's tracks
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let title_track = asset_server.load(SfxEvent::Hit.sound().path);
+    let game_track = asset_server.load(SfxEvent::Timing.sound().path);
+    let result_track = title_track.clone();
+    let mut music_player = app.world_mut().resource_mut::<MusicPlayer>();
+    music_player.set_track(AppState::MainMenu, title_track.clone());
+    music_player.set_track(AppState::InGame, game_track.clone());
+    music_player.set_track(AppState::GameOver, result_track.clone());
+    music_player.set_track(AppState::GameClear, result_track.clone());
+
+    common::run_frames(&mut app, 60);
+    let playing = music(&mut app);
+    assert_eq!(playing.len(), 1);
+    assert_eq!(playing[0].0, title_track);
+    assert!(playing[0].1 > 0.5 && playing[0].1 < 1.0);
+
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 3);
+    assert_eq!(
+        app.world().resource::<MusicPlayer>().playing(),
+        Some(&game_track)
+    );
+    let playing = music(&mut app);
+    assert_eq!(playing.len(), 2);
+    assert!(playing
+        .iter()
+        .any(|(track, _, fading_out)| *track == title_track && *fading_out));
+    assert!(playing
+        .iter()
+        .any(|(track, _, fading_out)| *track == game_track && !*fading_out));
+
+    // The title track fades out and the game's fades in fully
+    common::run_frames(&mut app, 120);
+    assert_eq!(music(&mut app), [(game_track.clone(), 1.0, false)]);
+
+    // Pausing has no track of its own and keeps the run's music
+    common::press_key(&mut app, KeyCode::Escape);
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::Paused);
+    assert_eq!(music(&mut app), [(game_track.clone(), 1.0, false)]);
+
+    // Both results share a track
+    common::set_state(&mut app, AppState::GameOver);
+    common::run_frames(&mut app, 2);
+    assert_eq!(
+        app.world().resource::<MusicPlayer>().playing(),
+        Some(&result_track)
+    );
+}
+
+#[test]
+fn missing_tracks_are_never_loaded() {
+    let mut app = common::headless_app(ArcadePlugin);
+    common::run_frames(&mut app, 10);
+    assert!(music(&mut app).is_empty());
+    assert_eq!(app.world().resource::<MusicPlayer>().playing(), None);
+
+    app.world_mut()
+        .resource_mut::<NextState<ActiveGame>>()
+        .set(ActiveGame(Some(CatchGamePlugin::INFO.id)));
+    common::wait_for_loading(&mut app);
+    common::run_frames(&mut app, 10);
+    assert!(music(&mut app).is_empty());
+    let asset_server = app.world().resource::<AssetServer>();
+    for path in GameMusic::DEFAULT.paths() {
+        assert!(
+            asset_server.get_handle::<AudioSource>(path).is_none(),
+            "{path}"
+        );
+    }
+}
+
+#[test]
+fn the_hub_plays_its_track() {
+    let mut app = common::headless_app(ArcadePlugin);
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let hub_track = asset_server.load(SfxEvent::Hit.sound().path);
+    app.world_mut()
+        .resource_mut::<MusicPlayer>()
+        .set_hub_track(SfxEvent::Hit.sound().path, &asset_server);
+
+    common::run_frames(&mut app, 2);
+    assert_eq!(
+        app.world().resource::<MusicPlayer>().playing(),
+        Some(&hub_track)
+    );
+}
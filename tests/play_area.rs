@@ -0,0 +1,118 @@
+mod common;
+
+use bevy::{
+    input::touch::{TouchInput, TouchPhase},
+    prelude::*,
+    render::camera::ScalingMode,
+    window::PrimaryWindow,
+};
+use ittokun_bevy_games::{
+    collision::Collider,
+    games::click_game::ClickGamePlugin,
+    play_area::{PlayArea, PlayAreaCamera},
+    AppState,
+};
+
+fn resize_window(app: &mut App, width: f32, height: f32) {
+    app.world_mut()
+        .query_filtered::<&mut Window, With<PrimaryWindow>>()
+        .single_mut(app.world_mut())
+        .resolution
+        .set(width, height);
+}
+
+fn touch(app: &mut App, id: u64, phase: TouchPhase, position: Vec2) {
+    let window = app
+        .world_mut()
+        .query_filtered::<Entity, With<PrimaryWindow>>()
+        .single(app.world());
+
+    app.world_mut().send_event(TouchInput {
+        phase,
+        position,
+        window,
+        force: None,
+        id,
+    });
+}
+
+fn ball_count(app: &mut App) -> usize {
+    app.world_mut()
+        .query_filtered::<(), With<Collider>>()
+        .iter(app.world())
+        .count()
+}
+
+#[test]
+fn a_taller_window_letterboxes_the_play_area() {
+    let mut app = common::headless_app(ClickGamePlugin);
+    common::run_frames(&mut app, 2);
+    assert_eq!(app.world().resource::<PlayArea>().scale(), 1.0);
+
+    // 1080x720 scaled by 4/3 leaves 240 pixels above and below
+    resize_window(&mut app, 1440.0, 1440.0);
+    common::run_frames(&mut app, 1);
+    let play_area = *app.world().resource::<PlayArea>();
+    assert_eq!(play_area.size(), Vec2::new(1080.0, 720.0));
+    assert!((play_area.scale() - 4.0 / 3.0).abs() < 1e-5);
+    assert_eq!(
+        play_area.window_to_world(Vec2::new(720.0, 720.0)),
+        Vec2::ZERO
+    );
+    assert_eq!(
+        play_area.window_to_world(Vec2::new(0.0, 240.0)),
+        Vec2::new(-540.0, 360.0)
+    );
+    assert_eq!(
+        play_area.world_to_window(Vec2::new(-540.0, 360.0)),
+        Vec2::new(0.0, 240.0)
+    );
+    assert_eq!(
+        play_area.window_to_world_delta(Vec2::new(4.0, 4.0)),
+        Vec2::new(3.0, -3.0)
+    );
+
+    let (camera, projection) = app
+        .world_mut()
+        .query_filtered::<(&Camera, &OrthographicProjection), With<PlayAreaCamera>>()
+        .single(app.world());
+    let viewport = camera.viewport.as_ref().unwrap();
+    assert_eq!(viewport.physical_position, UVec2::new(0, 240));
+    assert_eq!(viewport.physical_size, UVec2::new(1440, 960));
+    assert!(matches!(
+        projection.scaling_mode,
+        ScalingMode::Fixed {
+            width: 1080.0,
+            height: 720.0
+        }
+    ));
+}
+
+#[test]
+fn taps_pop_balls_through_the_letterbox() {
+    let mut app = common::headless_app(ClickGamePlugin);
+    common::run_frames(&mut app, 2);
+    resize_window(&mut app, 2160.0, 1640.0);
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+
+    let ball_position = app
+        .world_mut()
+        .query_filtered::<&Transform, With<Collider>>()
+        .iter(app.world())
+        .next()
+        .unwrap()
+        .translation
+        .truncate();
+    let balls = ball_count(&mut app);
+
+    // Twice the size, centered 100 pixels down
+    let window_position = Vec2::new(
+        (ball_position.x + 540.0) * 2.0,
+        (360.0 - ball_position.y) * 2.0 + 100.0,
+    );
+    touch(&mut app, 1, TouchPhase::Started, window_position);
+    common::run_frames(&mut app, 1);
+    assert!(ball_count(&mut app) < balls);
+}
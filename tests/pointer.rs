@@ -0,0 +1,86 @@
+use bevy::{
+    input::{
+        touch::{TouchInput, TouchPhase},
+        InputPlugin,
+    },
+    prelude::*,
+    window::PrimaryWindow,
+};
+use ittokun_bevy_games::{
+    input::{Action, InputBinding, InputMap, InputMapPlugin},
+    play_area::PlayArea,
+    pointer::{PointerId, Pointers},
+};
+
+fn pointer_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin))
+        .add_plugins(InputMapPlugin::<Action>::default())
+        .insert_resource(InputMap::new("pointer_test").bind(Action::Jump, InputBinding::Pointer));
+    app.world_mut().spawn((Window::default(), PrimaryWindow));
+    app
+}
+
+fn touch(app: &mut App, id: u64, phase: TouchPhase, position: Vec2) {
+    let window = app
+        .world_mut()
+        .query_filtered::<Entity, With<PrimaryWindow>>()
+        .single(app.world());
+
+    app.world_mut().send_event(TouchInput {
+        phase,
+        position,
+        window,
+        force: None,
+        id,
+    });
+}
+
+#[test]
+fn touches_become_pointers() {
+    let mut app = pointer_app();
+    let play_area = PlayArea::new(Vec2::new(1280.0, 720.0));
+
+    touch(&mut app, 1, TouchPhase::Started, Vec2::new(100.0, 100.0));
+    touch(&mut app, 2, TouchPhase::Started, Vec2::new(300.0, 200.0));
+    app.update();
+    let pointers = app.world().resource::<Pointers>();
+    assert_eq!(pointers.iter_just_pressed().count(), 2);
+    assert_eq!(pointers.world_drag(&play_area), Vec2::ZERO);
+
+    // Screen y grows downwards, world y upwards
+    touch(&mut app, 1, TouchPhase::Moved, Vec2::new(110.0, 90.0));
+    app.update();
+    let pointers = app.world().resource::<Pointers>();
+    assert!(!pointers.any_just_pressed());
+    assert_eq!(pointers.world_drag(&play_area), Vec2::new(10.0, 10.0));
+
+    touch(&mut app, 1, TouchPhase::Ended, Vec2::new(110.0, 90.0));
+    app.update();
+    let ids: Vec<PointerId> = app
+        .world()
+        .resource::<Pointers>()
+        .iter()
+        .map(|pointer| pointer.id)
+        .collect();
+    assert_eq!(ids, vec![PointerId::Touch(2)]);
+}
+
+#[test]
+fn taps_press_pointer_bound_actions() {
+    let mut app = pointer_app();
+
+    touch(&mut app, 1, TouchPhase::Started, Vec2::new(100.0, 100.0));
+    app.update();
+    assert!(app
+        .world()
+        .resource::<ButtonInput<Action>>()
+        .just_pressed(Action::Jump));
+
+    touch(&mut app, 1, TouchPhase::Ended, Vec2::new(100.0, 100.0));
+    app.update();
+    assert!(app
+        .world()
+        .resource::<ButtonInput<Action>>()
+        .just_released(Action::Jump));
+}
@@ -0,0 +1,174 @@
+mod common;
+
+use bevy::prelude::*;
+use ittokun_bevy_games::{
+    high_scores::ScoreOrder,
+    input::{Action, InputMap},
+    loading::AssetManifest,
+    mini_game::{GameSystems, HostedGamePlugin, Localized, MiniGame, MiniGameInfo, MiniGameSet},
+    music::GameMusic,
+    replay::{Replay, ReplayPlayer, ReplayRecorder, ReplayTick},
+    result_screen::{GameFinished, Outcome},
+    resuming,
+    rng::GameRng,
+    AppState, GameplaySet,
+};
+use rand::RngCore;
+
+const GAME_ID: &str = "replay_test";
+const RUN_TICKS: u32 = 300;
+
+// Not one of the games: a run that only plays out the same from the same input and seed
+struct TallyGamePlugin;
+
+impl MiniGame for TallyGamePlugin {
+    const INFO: MiniGameInfo = MiniGameInfo {
+        id: GAME_ID,
+        name: Localized {
+            en: "Tally",
+            ja: "Tally",
+        },
+        description: Localized { en: "", ja: "" },
+        controls: Localized { en: "", ja: "" },
+        window_size: Vec2::new(400.0, 400.0),
+        colors: &[],
+        score_order: ScoreOrder::HigherIsBetter,
+        assets: AssetManifest::EMPTY,
+        music: GameMusic::DEFAULT,
+    };
+}
+
+impl Plugin for TallyGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(HostedGamePlugin::<Self>::default())
+            .add_systems(
+                OnEnter(Self::active()),
+                insert_input_map.in_set(MiniGameSet::Setup),
+            )
+            .add_systems(
+                OnEnter(AppState::InGame),
+                reset_tally
+                    .run_if(not(resuming))
+                    .in_set(GameSystems(GAME_ID)),
+            )
+            .add_systems(
+                FixedUpdate,
+                count
+                    .in_set(GameplaySet)
+                    .in_set(GameSystems(GAME_ID))
+                    .run_if(in_state(AppState::InGame)),
+            );
+    }
+}
+
+#[derive(Resource, Clone, Default, PartialEq, Debug)]
+struct Tally {
+    ticks: u32,
+    held_right: u32,
+    draws: Vec<u32>,
+}
+
+fn insert_input_map(mut commands: Commands) {
+    commands.insert_resource(
+        InputMap::new(GAME_ID)
+            .bind(Action::Jump, KeyCode::Space)
+            .bind(Action::MoveRight, KeyCode::ArrowRight),
+    );
+}
+
+fn reset_tally(mut commands: Commands) {
+    commands.insert_resource(Tally::default());
+}
+
+fn count(
+    action_input: Res<ButtonInput<Action>>,
+    mut rng: ResMut<GameRng>,
+    mut tally: ResMut<Tally>,
+    mut finished_events: EventWriter<GameFinished>,
+) {
+    tally.ticks += 1;
+    if action_input.pressed(Action::MoveRight) {
+        tally.held_right += 1;
+    }
+    if action_input.just_pressed(Action::Jump) {
+        let draw = rng.next_u32() % 100;
+        tally.draws.push(draw);
+    }
+    if tally.ticks == RUN_TICKS {
+        finished_events
+            .send(GameFinished::new(Outcome::GameOver).with_score(tally.draws.len() as f32));
+    }
+}
+
+fn tick(pressed: Vec<Action>, entered: bool) -> ReplayTick {
+    ReplayTick {
+        repeat: 1,
+        delta_nanos: 16_666_667,
+        entered,
+        pressed,
+        axes: Vec::new(),
+        pointers: Vec::new(),
+    }
+}
+
+#[test]
+fn identical_frames_share_a_tick() {
+    let mut replay = Replay::new("replay_test", 42);
+    replay.push(tick(Vec::new(), true));
+    replay.push(tick(Vec::new(), false));
+    replay.push(tick(vec![Action::Jump], false));
+    replay.push(tick(vec![Action::Jump], false));
+    replay.push(tick(vec![Action::Jump], false));
+    // Entering again restarts the fixed timestep, so it is never merged away
+    replay.push(tick(vec![Action::Jump], true));
+
+    let repeats: Vec<u32> = replay.ticks.iter().map(|tick| tick.repeat).collect();
+    assert_eq!(repeats, vec![2, 3, 1]);
+    assert_eq!(replay.frame_count(), 6);
+
+    let json = serde_json::to_string(&replay).unwrap();
+    assert_eq!(serde_json::from_str::<Replay>(&json).unwrap(), replay);
+}
+
+#[test]
+fn a_saved_run_plays_back_the_same() {
+    let mut app = common::headless_app(TallyGamePlugin);
+    common::start_run(&mut app);
+    for frame in 0..RUN_TICKS as usize * 2 {
+        if common::current_state(&app) != AppState::InGame {
+            break;
+        }
+        match frame % 45 {
+            0 => common::press_key(&mut app, KeyCode::Space),
+            5 => common::release_key(&mut app, KeyCode::Space),
+            20 => common::press_key(&mut app, KeyCode::ArrowRight),
+            38 => common::release_key(&mut app, KeyCode::ArrowRight),
+            _ => {}
+        }
+        app.update();
+    }
+    assert_eq!(common::current_state(&app), AppState::GameOver);
+    let recorded = app.world().resource::<Tally>().clone();
+    assert!(
+        !recorded.draws.is_empty() && recorded.held_right > 0,
+        "{recorded:?}"
+    );
+
+    let replay = app
+        .world()
+        .resource::<ReplayRecorder>()
+        .last
+        .clone()
+        .unwrap();
+    let json = serde_json::to_string(&replay).unwrap();
+    let loaded: Replay = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, replay);
+
+    let mut replay_app = common::headless_app(TallyGamePlugin);
+    replay_app
+        .insert_resource(GameRng::new(loaded.seed))
+        .insert_resource(ReplayPlayer::new(loaded));
+    common::run_frames(&mut replay_app, RUN_TICKS as usize * 2);
+    assert_eq!(common::current_state(&replay_app), AppState::GameOver);
+    assert_eq!(*replay_app.world().resource::<Tally>(), recorded);
+}
@@ -0,0 +1,19 @@
+use ittokun_bevy_games::rng::GameRng;
+use rand::Rng;
+
+fn draws(rng: &mut GameRng) -> Vec<u32> {
+    (0..16).map(|_| rng.gen_range(0..1000)).collect()
+}
+
+#[test]
+fn same_seed_draws_the_same_numbers() {
+    let mut rng = GameRng::new(1234);
+    let first = draws(&mut rng);
+
+    assert_eq!(draws(&mut GameRng::new(1234)), first);
+    assert_ne!(draws(&mut GameRng::new(4321)), first);
+
+    rng.reset();
+    assert_eq!(draws(&mut rng), first);
+    assert_eq!(rng.seed(), 1234);
+}
@@ -0,0 +1,197 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use ittokun_bevy_games::{
+    games::run_and_jump::RunAndJumpPlugin,
+    interpolation::Interpolated,
+    menu::Menu,
+    result_screen::{GameFinished, Outcome, ResultButton, ResultPanel},
+    AppState,
+};
+
+// How far the player has run on the first stage when each jump clears its gaps and platforms,
+// with a few frames of slack either way
+const FIRST_STAGE_JUMPS: [f32; 7] = [-128.0, 60.0, 85.0, 342.0, 428.0, 920.0, 1015.0];
+
+#[test]
+fn runs_through_game_over() {
+    // Never jumping, it falls into the first gap
+    common::smoke_test(
+        common::headless_app(RunAndJumpPlugin),
+        AppState::GameOver,
+        600,
+        |_, _| {},
+    );
+}
+
+#[test]
+fn runs_through_game_clear() {
+    let mut jumps = FIRST_STAGE_JUMPS.iter().peekable();
+    let mut jumped = false;
+    common::smoke_test(
+        common::headless_app(RunAndJumpPlugin),
+        AppState::GameClear,
+        1200,
+        |app, _| {
+            if jumped {
+                common::release_key(app, KeyCode::Space);
+                jumped = false;
+                return;
+            }
+            let x = common::player_position(app).map_or(f32::MIN, |position| position.x);
+            if jumps.next_if(|jump| x >= **jump).is_some() {
+                common::press_key(app, KeyCode::Space);
+                jumped = true;
+            }
+        },
+    );
+}
+
+#[derive(Resource, Default)]
+struct Trajectory(Vec<Vec3>);
+
+// Only the player is interpolated in this game
+fn record_trajectory(
+    player_query: Query<&Transform, With<Interpolated>>,
+    mut trajectory: ResMut<Trajectory>,
+) {
+    trajectory
+        .0
+        .extend(player_query.iter().map(|transform| transform.translation));
+}
+
+fn trajectory_at(frame_rate: f64) -> Vec<Vec3> {
+    let mut app = common::headless_app(RunAndJumpPlugin);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+        1.0 / frame_rate,
+    )))
+    .init_resource::<Trajectory>()
+    .add_systems(
+        FixedPostUpdate,
+        record_trajectory.run_if(in_state(AppState::InGame)),
+    );
+
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, frame_rate as usize);
+    // Jump once it has landed on the first tile
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, (2.0 * frame_rate) as usize);
+    app.world().resource::<Trajectory>().0.clone()
+}
+
+// The peak height and the distance run from take-off to landing
+fn jump(trajectory: &[Vec3]) -> (f32, f32) {
+    let take_off = trajectory
+        .windows(2)
+        .position(|ticks| ticks[1].y > ticks[0].y)
+        .unwrap();
+    let start = trajectory[take_off];
+    let peak = trajectory[take_off..]
+        .iter()
+        .map(|position| position.y)
+        .fold(f32::MIN, f32::max);
+    let landing = trajectory[take_off + 1..]
+        .windows(2)
+        .find(|ticks| ticks[0].y > start.y && ticks[1].y <= start.y)
+        .map(|ticks| ticks[1])
+        .unwrap();
+    (peak - start.y, landing.x - start.x)
+}
+
+#[test]
+fn frame_rate_does_not_change_the_jump() {
+    let (height_60, distance_60) = jump(&trajectory_at(60.0));
+    assert!(
+        height_60 > 10.0 && distance_60 > 10.0,
+        "{height_60} {distance_60}"
+    );
+
+    for frame_rate in [30.0, 144.0] {
+        let (height, distance) = jump(&trajectory_at(frame_rate));
+        assert!(
+            (height - height_60).abs() < 0.01,
+            "{height} at {frame_rate} fps"
+        );
+        assert!(
+            (distance - distance_60).abs() < 0.01,
+            "{distance} at {frame_rate} fps"
+        );
+    }
+}
+
+// Each stage has its own layout of tiles
+fn tile_layout(app: &mut App) -> Vec<(i32, i32)> {
+    let mut layout: Vec<_> = app
+        .world_mut()
+        .query_filtered::<&Transform, With<Sprite>>()
+        .iter(app.world())
+        .map(|transform| {
+            (
+                transform.translation.x as i32,
+                transform.translation.y as i32,
+            )
+        })
+        .collect();
+    layout.sort();
+    layout
+}
+
+fn result_panels(app: &mut App) -> usize {
+    app.world_mut()
+        .query_filtered::<(), With<ResultPanel>>()
+        .iter(app.world())
+        .count()
+}
+
+#[test]
+fn the_result_screen_goes_on_to_the_next_stage_or_back_to_the_title() {
+    let mut app = common::headless_app(RunAndJumpPlugin);
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 2);
+    let first_stage = tile_layout(&mut app);
+
+    app.world_mut().send_event(
+        GameFinished::new(Outcome::GameClear)
+            .with_score(1.0)
+            .with_next_stage(),
+    );
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::GameClear);
+    assert_eq!(result_panels(&mut app), 1);
+    assert_eq!(
+        app.world().resource::<Menu<ResultButton>>().selected(),
+        Some(ResultButton::NextStage)
+    );
+
+    common::press_key(&mut app, KeyCode::Enter);
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+    assert_eq!(result_panels(&mut app), 0);
+    assert_ne!(tile_layout(&mut app), first_stage);
+
+    app.world_mut()
+        .send_event(GameFinished::new(Outcome::GameOver));
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::GameOver);
+    assert_eq!(
+        app.world().resource::<Menu<ResultButton>>().selected(),
+        Some(ResultButton::Retry)
+    );
+
+    common::press_key(&mut app, KeyCode::ArrowRight);
+    common::run_frames(&mut app, 1);
+    assert_eq!(
+        app.world().resource::<Menu<ResultButton>>().selected(),
+        Some(ResultButton::Title)
+    );
+    common::press_key(&mut app, KeyCode::Enter);
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::MainMenu);
+
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 2);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+    assert_eq!(tile_layout(&mut app), first_stage);
+}
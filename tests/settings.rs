@@ -0,0 +1,188 @@
+mod common;
+
+use bevy::{
+    audio::GlobalVolume,
+    input::{
+        keyboard::{Key, KeyboardInput, NativeKey},
+        ButtonState,
+    },
+    prelude::*,
+};
+use ittokun_bevy_games::{
+    games::catch_game::CatchGamePlugin,
+    input::{Action, InputBinding, InputMap},
+    menu::Menu,
+    pause_menu::PauseScreen,
+    settings::{Settings, SettingsButton, SettingsScreen},
+    storage, AppState, TitleButton,
+};
+
+fn tap(app: &mut App, key_code: KeyCode) {
+    common::press_key(app, key_code);
+    release(app, key_code);
+}
+
+// With the text it types, which `tap` leaves out
+fn type_key(app: &mut App, key_code: KeyCode, logical_key: Key) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key,
+        state: ButtonState::Pressed,
+        window: Entity::PLACEHOLDER,
+    });
+    release(app, key_code);
+}
+
+fn release(app: &mut App, key_code: KeyCode) {
+    common::run_frames(app, 1);
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state: ButtonState::Released,
+        window: Entity::PLACEHOLDER,
+    });
+    common::run_frames(app, 2);
+}
+
+fn selected(app: &App) -> Option<SettingsButton> {
+    app.world().resource::<Menu<SettingsButton>>().selected()
+}
+
+#[test]
+fn the_settings_screen_changes_saves_and_applies_settings() {
+    let mut app = common::headless_app(CatchGamePlugin);
+    // Rebinding saves, so keep the game's own bindings untouched. They are loaded as the game
+    // starts, in the first frame.
+    common::run_frames(&mut app, 1);
+    app.insert_resource(
+        InputMap::new("settings_test")
+            .bind(Action::Jump, KeyCode::Space)
+            .bind(Action::Jump, GamepadButtonType::South),
+    );
+
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 2);
+    tap(&mut app, KeyCode::Escape);
+    assert_eq!(common::current_state(&app), AppState::Paused);
+
+    // Resume, Restart, Settings
+    tap(&mut app, KeyCode::ArrowDown);
+    tap(&mut app, KeyCode::ArrowDown);
+    tap(&mut app, KeyCode::Enter);
+    assert_eq!(
+        *app.world().resource::<State<PauseScreen>>().get(),
+        PauseScreen::Settings
+    );
+    assert_eq!(selected(&app), Some(SettingsButton::Audio));
+
+    tap(&mut app, KeyCode::Enter);
+    assert_eq!(selected(&app), Some(SettingsButton::MasterVolume));
+    let master_volume = app.world().resource::<Settings>().master_volume;
+    tap(&mut app, KeyCode::Enter);
+    let settings = app.world().resource::<Settings>().clone();
+    assert_ne!(settings.master_volume, master_volume);
+    assert_eq!(
+        app.world().resource::<GlobalVolume>().volume.get(),
+        settings.master_volume
+    );
+    let saved: Settings = serde_json::from_str(&storage::load("settings.json").unwrap()).unwrap();
+    assert_eq!(saved, settings);
+
+    // Back to the first page, then Audio, Display, Key Bindings
+    tap(&mut app, KeyCode::Escape);
+    tap(&mut app, KeyCode::ArrowDown);
+    tap(&mut app, KeyCode::ArrowDown);
+    tap(&mut app, KeyCode::Enter);
+    assert_eq!(selected(&app), Some(SettingsButton::Binding(Action::Jump)));
+    tap(&mut app, KeyCode::Enter);
+    tap(&mut app, KeyCode::KeyS);
+    let input_map = app.world().resource::<InputMap<Action>>();
+    assert_eq!(
+        input_map.bindings(Action::Jump),
+        [
+            InputBinding::Key(KeyCode::KeyS),
+            InputBinding::Gamepad(GamepadButtonType::South)
+        ]
+    );
+    // S also moves through menus, but went to the binding instead
+    assert_eq!(selected(&app), Some(SettingsButton::Binding(Action::Jump)));
+
+    // Back to the first page, then its last setting, the name
+    tap(&mut app, KeyCode::Escape);
+    for _ in 0..4 {
+        tap(&mut app, KeyCode::ArrowDown);
+    }
+    assert_eq!(selected(&app), Some(SettingsButton::PlayerName));
+    tap(&mut app, KeyCode::Enter);
+    let name = app.world().resource::<Settings>().player_name.clone();
+    for _ in name.chars() {
+        type_key(&mut app, KeyCode::Backspace, Key::Backspace);
+    }
+    for (key_code, letter) in [
+        (KeyCode::KeyS, "S"),
+        (KeyCode::KeyA, "a"),
+        (KeyCode::KeyM, "m"),
+    ] {
+        type_key(&mut app, key_code, Key::Character(letter.into()));
+    }
+    type_key(&mut app, KeyCode::Enter, Key::Enter);
+    let settings = app.world().resource::<Settings>().clone();
+    assert_eq!(settings.player_name, "Sam");
+    let saved: Settings = serde_json::from_str(&storage::load("settings.json").unwrap()).unwrap();
+    assert_eq!(saved, settings);
+    assert_eq!(selected(&app), Some(SettingsButton::PlayerName));
+
+    tap(&mut app, KeyCode::Escape);
+    assert_eq!(
+        *app.world().resource::<State<PauseScreen>>().get(),
+        PauseScreen::Menu
+    );
+    tap(&mut app, KeyCode::Escape);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+}
+
+#[test]
+fn the_title_screen_opens_the_settings_before_a_run() {
+    let mut app = common::headless_app(CatchGamePlugin);
+    assert_eq!(common::current_state(&app), AppState::MainMenu);
+
+    // Start, Settings
+    tap(&mut app, KeyCode::ArrowRight);
+    tap(&mut app, KeyCode::Enter);
+    assert_eq!(
+        *app.world().resource::<State<SettingsScreen>>().get(),
+        SettingsScreen::Open
+    );
+    assert_eq!(common::current_state(&app), AppState::MainMenu);
+    assert!(app.world().get_resource::<Menu<TitleButton>>().is_none());
+    assert_eq!(selected(&app), Some(SettingsButton::Audio));
+
+    // Audio, Display, Key Bindings, Language
+    for _ in 0..3 {
+        tap(&mut app, KeyCode::ArrowDown);
+    }
+    let language = app.world().resource::<Settings>().language;
+    tap(&mut app, KeyCode::Enter);
+    assert_ne!(app.world().resource::<Settings>().language, language);
+
+    tap(&mut app, KeyCode::Escape);
+    assert_eq!(
+        *app.world().resource::<State<SettingsScreen>>().get(),
+        SettingsScreen::Closed
+    );
+    assert_eq!(common::current_state(&app), AppState::MainMenu);
+    let menu = app.world().resource::<Menu<TitleButton>>();
+    assert_eq!(menu.selected(), Some(TitleButton::Settings));
+
+    // Any key other than the menu's still starts
+    tap(&mut app, KeyCode::KeyX);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+    assert!(app.world().get_resource::<Menu<TitleButton>>().is_none());
+}
+
+#[test]
+fn the_player_name_is_not_taken_from_the_account() {
+    std::env::set_var("USER", "account_name");
+    std::env::set_var("USERNAME", "account_name");
+    assert_eq!(Settings::default().player_name, "Player");
+}
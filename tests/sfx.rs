@@ -0,0 +1,57 @@
+mod common;
+
+use bevy::prelude::*;
+use ittokun_bevy_games::{
+    games::catch_game::CatchGamePlugin,
+    settings::Settings,
+    sfx::{Sfx, SfxEvent},
+};
+
+fn playing(app: &mut App, event: SfxEvent) -> Vec<PlaybackSettings> {
+    app.world_mut()
+        .query::<(&Sfx, &PlaybackSettings)>()
+        .iter(app.world())
+        .filter(|(Sfx(other), _)| *other == event)
+        .map(|(_, settings)| *settings)
+        .collect()
+}
+
+#[test]
+fn sound_effects_are_capped_and_follow_the_sfx_volume() {
+    let mut app = common::headless_app(CatchGamePlugin);
+    app.world_mut().resource_mut::<Settings>().sfx_volume = 0.5;
+
+    // Nothing plays out without an audio device, so every copy stays
+    for _ in 0..10 {
+        app.world_mut().send_event(SfxEvent::Hit);
+    }
+    common::run_frames(&mut app, 1);
+    for _ in 0..10 {
+        app.world_mut().send_event(SfxEvent::Hit);
+    }
+    common::run_frames(&mut app, 1);
+
+    let sound = SfxEvent::Hit.sound();
+    let hits = playing(&mut app, SfxEvent::Hit);
+    assert_eq!(hits.len(), sound.max_playing);
+    for settings in hits {
+        assert_eq!(settings.volume.get(), sound.volume * 0.5);
+        assert!((settings.speed - sound.speed).abs() <= sound.speed_variation);
+    }
+
+    // Each effect has its own cap
+    app.world_mut().send_event(SfxEvent::Pickup);
+    common::run_frames(&mut app, 1);
+    assert_eq!(playing(&mut app, SfxEvent::Pickup).len(), 1);
+}
+
+#[test]
+fn each_effect_has_its_own_sound() {
+    let mut paths: Vec<&str> = SfxEvent::ALL
+        .iter()
+        .map(|event| event.sound().path)
+        .collect();
+    paths.sort();
+    paths.dedup();
+    assert_eq!(paths.len(), SfxEvent::ALL.len());
+}
@@ -0,0 +1,14 @@
+mod common;
+
+use ittokun_bevy_games::{games::shooting_2d::Shooting2dPlugin, AppState};
+
+#[test]
+fn runs_through_every_state() {
+    // The enemy shoots down a player that stays put
+    common::smoke_test(
+        common::headless_app(Shooting2dPlugin),
+        AppState::GameOver,
+        2400,
+        |_, _| {},
+    );
+}
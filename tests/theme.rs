@@ -0,0 +1,154 @@
+mod common;
+
+use bevy::prelude::*;
+use ittokun_bevy_games::{
+    games::{
+        catch_game::CatchGamePlugin, shooting_2d::Shooting2dPlugin, timing_game::TimingGamePlugin,
+    },
+    mini_game::MiniGame,
+    pause_menu::{PausePanel, PauseScreen},
+    settings::{Settings, SettingsPanel},
+    theme::{Palette, Theme, ThemeColor, Themed},
+    AppState,
+};
+
+fn distance(a: Color, b: Color) -> f32 {
+    let (a, b) = (a.to_srgba(), b.to_srgba());
+    Vec3::new(a.red - b.red, a.green - b.green, a.blue - b.blue).length()
+}
+
+fn themed_colors(app: &mut App, color: ThemeColor) -> Vec<Color> {
+    let handles: Vec<Handle<ColorMaterial>> = app
+        .world_mut()
+        .query::<(&Themed, &Handle<ColorMaterial>)>()
+        .iter(app.world())
+        .filter(|(themed, _)| themed.0 == color)
+        .map(|(_, handle)| handle.clone())
+        .collect();
+    let materials = app.world().resource::<Assets<ColorMaterial>>();
+    handles
+        .iter()
+        .map(|handle| materials.get(handle).unwrap().color)
+        .collect()
+}
+
+#[test]
+fn every_palette_keeps_what_matters_apart() {
+    for palette in Palette::ALL {
+        let theme = Theme::new(palette);
+        assert_eq!(theme.palette, palette);
+        for (a, b) in [
+            (ThemeColor::Good, ThemeColor::Bad),
+            (ThemeColor::Player, ThemeColor::Enemy),
+            (ThemeColor::Ground, ThemeColor::Goal),
+            (ThemeColor::Background, ThemeColor::Text),
+            (ThemeColor::DarkBackground, ThemeColor::LightText),
+        ] {
+            assert!(
+                distance(theme.color(a), theme.color(b)) > 0.3,
+                "{palette:?} {a:?} {b:?}"
+            );
+        }
+    }
+}
+
+fn panel_colors<T: Component>(app: &mut App) -> Vec<Color> {
+    let mut colors: Vec<Color> = app
+        .world_mut()
+        .query_filtered::<&BackgroundColor, With<T>>()
+        .iter(app.world())
+        .map(|background_color| background_color.0)
+        .collect();
+    let children: Vec<Entity> = app
+        .world_mut()
+        .query_filtered::<&Children, With<T>>()
+        .iter(app.world())
+        .flat_map(|children| children.iter().copied())
+        .collect();
+    for child in children {
+        colors.extend(
+            app.world()
+                .get::<BackgroundColor>(child)
+                .map(|color| color.0),
+        );
+    }
+    colors
+}
+
+#[test]
+fn the_default_palette_keeps_each_games_own_colours() {
+    let default = Theme::new(Palette::Default);
+    assert_eq!(
+        default
+            .with_game_colors(TimingGamePlugin::INFO.colors)
+            .background,
+        Color::WHITE
+    );
+    // A palette's own colours win over the game's
+    let high_contrast = Theme::new(Palette::HighContrast);
+    let shooting_2d = high_contrast.with_game_colors(Shooting2dPlugin::INFO.colors);
+    assert_eq!(shooting_2d.player, high_contrast.player);
+    // The colour-blind palettes keep what they don't change
+    let deuteranopia =
+        Theme::new(Palette::Deuteranopia).with_game_colors(Shooting2dPlugin::INFO.colors);
+    assert_eq!(deuteranopia.dark_background, Color::BLACK);
+    assert_eq!(
+        deuteranopia.player,
+        Theme::new(Palette::Deuteranopia).player
+    );
+
+    let mut app = common::headless_app(Shooting2dPlugin);
+    // Another test may have saved its palette
+    app.world_mut().resource_mut::<Settings>().palette = Palette::Default;
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 3);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+    assert_eq!(app.world().resource::<ClearColor>().0, Color::BLACK);
+    let players = themed_colors(&mut app, ThemeColor::Player);
+    assert!(!players.is_empty());
+    assert!(players
+        .iter()
+        .all(|color| *color == Color::srgb(0.3, 0.9, 0.3)));
+}
+
+#[test]
+fn changing_the_palette_recolours_the_game() {
+    let mut app = common::headless_app(CatchGamePlugin);
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 3);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+    let theme = *app.world().resource::<Theme>();
+    assert_eq!(app.world().resource::<ClearColor>().0, theme.background);
+    assert_eq!(themed_colors(&mut app, ThemeColor::Player), [theme.player]);
+
+    app.world_mut().resource_mut::<Settings>().palette = Palette::HighContrast;
+    common::run_frames(&mut app, 1);
+    let theme = Theme::new(Palette::HighContrast).with_game_colors(CatchGamePlugin::INFO.colors);
+    assert_eq!(*app.world().resource::<Theme>(), theme);
+    assert_eq!(app.world().resource::<ClearColor>().0, theme.background);
+    assert_eq!(themed_colors(&mut app, ThemeColor::Player), [theme.player]);
+
+    // The menus are spawned in the palette
+    common::press_key(&mut app, KeyCode::Escape);
+    common::run_frames(&mut app, 3);
+    assert_eq!(common::current_state(&app), AppState::Paused);
+    let pause_colors = panel_colors::<PausePanel>(&mut app);
+    assert_eq!(pause_colors, [theme.backdrop, theme.panel]);
+
+    // And the settings page follows a palette chosen on it. Settings are saved there, so leave
+    // the default for the other tests.
+    app.world_mut()
+        .resource_mut::<NextState<PauseScreen>>()
+        .set(PauseScreen::Settings);
+    common::run_frames(&mut app, 2);
+    let high_contrast = panel_colors::<SettingsPanel>(&mut app);
+    assert_eq!(high_contrast, [theme.backdrop, theme.panel]);
+    app.world_mut().resource_mut::<Settings>().palette = Palette::Default;
+    common::run_frames(&mut app, 1);
+    let theme = Theme::new(Palette::Default).with_game_colors(CatchGamePlugin::INFO.colors);
+    assert_eq!(*app.world().resource::<Theme>(), theme);
+    assert_eq!(
+        panel_colors::<SettingsPanel>(&mut app),
+        [theme.backdrop, theme.panel]
+    );
+}
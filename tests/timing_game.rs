@@ -0,0 +1,19 @@
+mod common;
+
+use bevy::prelude::*;
+use ittokun_bevy_games::{games::timing_game::TimingGamePlugin, AppState};
+
+#[test]
+fn runs_through_every_state() {
+    // Stops the cue every third of a second until the attempts run out
+    common::smoke_test(
+        common::headless_app(TimingGamePlugin),
+        AppState::GameOver,
+        600,
+        |app, frame| match frame % 20 {
+            0 => common::press_key(app, KeyCode::Space),
+            1 => common::release_key(app, KeyCode::Space),
+            _ => {}
+        },
+    );
+}
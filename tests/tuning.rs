@@ -0,0 +1,96 @@
+mod common;
+
+use std::{thread, time::Duration};
+
+use bevy::prelude::*;
+use ittokun_bevy_games::{
+    games::{
+        catch_game::{CatchGamePlugin, CatchGameTuning},
+        click_game::ClickGameTuning,
+        flappy_bird::FlappyBirdTuning,
+        frogger::FroggerTuning,
+        run_and_jump::RunAndJumpTuning,
+        shooting_2d::Shooting2dTuning,
+        timing_game::TimingGameTuning,
+    },
+    tuning::{parse, Tuning, TuningError},
+    AppState,
+};
+
+#[test]
+fn every_shipped_tuning_is_in_range() {
+    fn check<T: Tuning>() {
+        let path = format!("{}/assets/{}", env!("CARGO_MANIFEST_DIR"), T::PATH);
+        let source = std::fs::read_to_string(&path).unwrap();
+        if let Err(error) = parse::<T>(&source) {
+            panic!("{path} {error}");
+        }
+    }
+
+    check::<CatchGameTuning>();
+    check::<ClickGameTuning>();
+    check::<FlappyBirdTuning>();
+    check::<FroggerTuning>();
+    check::<RunAndJumpTuning>();
+    check::<Shooting2dTuning>();
+    check::<TimingGameTuning>();
+}
+
+#[test]
+fn out_of_range_values_are_named() {
+    let source = CatchGameTuning::BUILT_IN
+        .replace("player_speed: 200.0", "player_speed: -5.0")
+        .replace("obstacle_speed: 150.0", "obstacle_speed: 0.0");
+    let error = parse::<CatchGameTuning>(&source).unwrap_err();
+    assert!(matches!(error, TuningError::OutOfRange(ref problems) if problems.len() == 2));
+    let message = error.to_string();
+    assert!(
+        message.contains("`player_speed` is -5, but must be from 1 to 2000"),
+        "{message}"
+    );
+    assert!(message.contains("`obstacle_speed` is 0"), "{message}");
+
+    let source =
+        TimingGameTuning::BUILT_IN.replace("slider_good_range: 60.0", "slider_good_range: 120.0");
+    let message = parse::<TimingGameTuning>(&source).unwrap_err().to_string();
+    assert!(message.contains("must widen"), "{message}");
+
+    let source = CatchGameTuning::BUILT_IN.replace("player_speed", "player_sped");
+    let error = parse::<CatchGameTuning>(&source).unwrap_err();
+    assert!(matches!(error, TuningError::Parse(_)), "{error}");
+    assert!(error.to_string().contains("player_sped"), "{error}");
+}
+
+#[test]
+fn a_changed_file_is_applied_while_the_game_runs() {
+    let mut app = common::headless_app(CatchGamePlugin);
+    common::press_key(&mut app, KeyCode::Space);
+    common::run_frames(&mut app, 3);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+    assert_eq!(
+        *app.world().resource::<CatchGameTuning>(),
+        CatchGameTuning::built_in()
+    );
+
+    // Loaded off the main thread
+    for _ in 0..100 {
+        if !app.world().resource::<Assets<CatchGameTuning>>().is_empty() {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+        common::run_frames(&mut app, 1);
+    }
+    common::run_frames(&mut app, 1);
+
+    // What the file watcher does on a save, without touching the shipped file
+    let tuned = CatchGameTuning {
+        player_speed: 400.0,
+        ..CatchGameTuning::built_in()
+    };
+    let mut tunings = app.world_mut().resource_mut::<Assets<CatchGameTuning>>();
+    let id = tunings.ids().next().unwrap();
+    *tunings.get_mut(id).unwrap() = tuned.clone();
+    common::run_frames(&mut app, 2);
+    assert_eq!(*app.world().resource::<CatchGameTuning>(), tuned);
+    assert_eq!(common::current_state(&app), AppState::InGame);
+}